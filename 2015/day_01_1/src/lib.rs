@@ -0,0 +1,41 @@
+use common::Result;
+
+/// Gets the final floor after following all instructions.
+pub fn get_floor(chars: impl Iterator<Item = char>) -> i32 {
+    chars
+        .map(|c| match c {
+            '(' => 1,
+            ')' => -1,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// The floor the instructions in the puzzle input take Santa to.
+///
+/// # Errors
+///
+/// Returns an `Err` if the input is not ASCII.
+pub fn part1(input: &str) -> Result<i32> {
+    let chars = input
+        .bytes()
+        .map(common::ascii_byte_to_char)
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(get_floor(chars.into_iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_floor, part1};
+
+    #[test]
+    fn test_get_floor() {
+        assert_eq!(get_floor("".chars()), 0);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("(()(()(\n"), Ok(3));
+        assert!(part1("((é").is_err());
+    }
+}
@@ -4,48 +4,10 @@
 //!   - No manual loops
 //!   - No external non-std dependencies
 
-use std::io::Read;
-
-/// Gets the final floor after following all instructions.
-fn get_floor(chars: impl Iterator<Item = char>) -> i32 {
-    chars
-        .map(|c| match c {
-            '(' => 1,
-            ')' => -1,
-            _ => 0,
-        })
-        .sum()
-}
-
-fn main() {
-    let file = std::fs::File::open("input/input.txt").unwrap();
-    let reader = std::io::BufReader::new(file);
-
-    let floor = get_floor(
-        reader
-            .bytes()
-            .map(|b| b.and_then(common::ascii_byte_to_char))
-            .map(Result::unwrap),
-    );
+fn main() -> common::Result<()> {
+    let input = common::input::load(env!("CARGO_MANIFEST_DIR"), 2015, 1)?;
+    let floor = day_01_1::part1(&input)?;
 
     println!("Floor: {floor}");
-}
-
-#[cfg(test)]
-mod tests {
-    use super::get_floor;
-
-    #[test]
-    fn test_get_floor() {
-        assert_eq!(get_floor("".chars()), 0);
-        assert_eq!(get_floor("(())".chars()), 0);
-        assert_eq!(get_floor("()()".chars()), 0);
-        assert_eq!(get_floor("(((".chars()), 3);
-        assert_eq!(get_floor("(()(()(".chars()), 3);
-        assert_eq!(get_floor("))(((((".chars()), 3);
-        assert_eq!(get_floor("())".chars()), -1);
-        assert_eq!(get_floor("))(".chars()), -1);
-        assert_eq!(get_floor(")))".chars()), -3);
-        assert_eq!(get_floor(")())())".chars()), -3);
-    }
+    Ok(())
 }
@@ -0,0 +1,108 @@
+use common::generate::Rng;
+use common::solution::{Result, Solution};
+use day_01_1::get_floor;
+pub use day_01_1::part1;
+
+/// Gets the position of the first character that causes the floor to go below 0.
+/// Returns None if the floor never goes below 0.
+#[must_use]
+pub fn get_basement_position(chars: impl Iterator<Item = char>) -> Option<usize> {
+    chars
+        .enumerate()
+        .scan(0, |floor, (position, c)| {
+            match c {
+                '(' => {
+                    *floor += 1;
+                }
+                ')' => {
+                    *floor -= 1;
+                }
+                _ => (),
+            }
+            Some((*floor, position))
+        })
+        .find(|(floor, _)| *floor < 0)
+        .map(|(_, position)| position + 1) // 1-indexed
+}
+
+/// The position of the instruction in the puzzle input that first takes Santa to the basement.
+///
+/// # Errors
+///
+/// Returns an `Err` if the input is not ASCII or Santa never enters the basement.
+pub fn part2(input: &str) -> Result<usize> {
+    Day01::part2(&Day01::parse(input)?)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+    const INPUT_SIZE: usize = 7000;
+
+    type Input = String;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input
+            .bytes()
+            .map(common::ascii_byte_to_char)
+            .collect::<std::result::Result<_, _>>()?)
+    }
+
+    fn part1(input: &String) -> Result<i32> {
+        Ok(get_floor(input.chars()))
+    }
+
+    fn part2(input: &String) -> Result<usize> {
+        get_basement_position(input.chars()).ok_or_else(|| "Santa never enters the basement".into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut input = rng.string(size, b"()");
+        // part 2 has no answer unless Santa ends up in the basement at some point
+        if get_basement_position(input.chars()).is_none() {
+            let floor = usize::try_from(get_floor(input.chars())).expect("never below 0");
+            input.extend(std::iter::repeat_n(')', floor + 1));
+        }
+        Some(input)
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_basement_position;
+
+    #[test]
+    fn test_get_basement_position() {
+        assert_eq!(get_basement_position("".chars()), None);
+        assert_eq!(get_basement_position("(".chars()), None);
+        assert_eq!(get_basement_position("()".chars()), None);
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(
+        super::Day01:
+        balanced,
+        alternating,
+        opening,
+        nested,
+        closing_first,
+        one_below,
+        one_below_closing_first,
+        three_below,
+        three_below_alternating,
+        basement_first,
+        basement_fifth,
+        basement_before_end,
+    );
+}
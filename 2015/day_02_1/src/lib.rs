@@ -1,14 +1,46 @@
+use common::{Error, Result};
+
 pub const DIMENSIONS: usize = 3;
 
 /// Extracts the side lengths from a string.
-pub fn extract_side_lengths(side_lengths: &str) -> Option<[u64; DIMENSIONS]> {
+///
+/// # Errors
+///
+/// Returns an `Err` if the string is not made up of three numbers separated by `x`.
+pub fn extract_side_lengths(side_lengths: &str) -> Result<[u64; DIMENSIONS]> {
     side_lengths
         .split('x')
         .map(str::parse::<u64>)
         .map(Result::ok)
-        .collect::<Option<Vec<u64>>>()?
-        .try_into()
-        .ok()
+        .collect::<Option<Vec<u64>>>()
+        .and_then(|lengths| lengths.try_into().ok())
+        .ok_or_else(|| Error::new("invalid box, expected LxWxH").with_text(side_lengths))
+}
+
+/// Calculates the wrapping area of a box with the given side lengths.
+#[must_use]
+pub fn wrapping_area(side_lengths: [u64; DIMENSIONS]) -> u64 {
+    // after sorting, the smallest side is defined by the first two elements
+    let mut side_lengths = side_lengths;
+    side_lengths.sort_unstable();
+
+    let mut side_areas = side_lengths
+        .iter()
+        .zip(side_lengths.iter().cycle().skip(1))
+        .map(|(a, b)| a * b)
+        .peekable();
+
+    // we need the slack once and the other sides twice each
+    *side_areas.peek().unwrap() + 2 * side_areas.sum::<u64>()
+}
+
+/// Calculates the wrapping area of a box with the given dimensions as a string.
+///
+/// # Errors
+///
+/// Returns an `Err` if the dimensions are invalid.
+pub fn wrapping_area_from_string(side_lengths: &str) -> Result<u64> {
+    extract_side_lengths(side_lengths).map(wrapping_area)
 }
 
 #[cfg(test)]
@@ -17,8 +49,24 @@ mod tests {
 
     #[test]
     fn test_extract_side_lengths() {
-        assert_eq!(extract_side_lengths("2x3x4"), Some([2, 3, 4]));
-        assert_eq!(extract_side_lengths("1x1x10"), Some([1, 1, 10]));
-        assert!(extract_side_lengths("1x1x").is_none());
+        assert_eq!(extract_side_lengths("2x3x4"), Ok([2, 3, 4]));
+        assert_eq!(extract_side_lengths("1x1x10"), Ok([1, 1, 10]));
+        assert!(extract_side_lengths("1x1x").is_err());
+        assert!(extract_side_lengths("1x1x1x1").is_err());
+    }
+
+    #[test]
+    fn test_wrapping_area() {
+        assert_eq!(wrapping_area([2, 3, 4]), 58);
+        assert_eq!(wrapping_area([1, 1, 10]), 43);
+        assert_eq!(wrapping_area([10, 1, 1]), 43);
+        assert_eq!(wrapping_area([1, 10, 1]), 43);
+    }
+
+    #[test]
+    fn test_wrapping_area_from_string() {
+        assert_eq!(wrapping_area_from_string("2x3x4"), Ok(58));
+        assert_eq!(wrapping_area_from_string("1x1x10"), Ok(43));
+        assert!(wrapping_area_from_string("1x1x").is_err());
     }
 }
@@ -0,0 +1,85 @@
+use common::generate::Rng;
+use common::solution::{Result, Solution};
+use common::LineParser;
+use day_02_1::{extract_side_lengths, wrapping_area, DIMENSIONS};
+
+/// Calculates the wrapping area of a box with the given side lengths.
+fn ribbon_length(side_lengths: [u64; DIMENSIONS]) -> u64 {
+    // after sorting, the smallest side is defined by the first two elements
+    let mut side_lengths = side_lengths;
+    side_lengths.sort_unstable();
+
+    // smallest side perimeter + volume
+    2 * (side_lengths[0] + side_lengths[1]) + side_lengths.iter().product::<u64>()
+}
+
+/// Calculates the wrapping area of a box with the given dimensions as a string.
+#[cfg(test)]
+fn ribbon_length_from_string(side_lengths: &str) -> Result<u64> {
+    extract_side_lengths(side_lengths).map(ribbon_length)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
+    const INPUT_SIZE: usize = 1000;
+
+    type Input = Vec<[u64; DIMENSIONS]>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        LineParser::new().parse_with(input, extract_side_lengths)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64> {
+        Ok(input.iter().copied().map(wrapping_area).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64> {
+        Ok(input.iter().copied().map(ribbon_length).sum())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(
+            (0..size)
+                .map(|_| {
+                    let [l, w, h] = [(); DIMENSIONS].map(|()| rng.range(1..=30));
+                    format!("{l}x{w}x{h}\n")
+                })
+                .collect(),
+        )
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ribbon_length() {
+        assert_eq!(ribbon_length([2, 3, 4]), 34);
+        assert_eq!(ribbon_length([1, 1, 10]), 14);
+        assert_eq!(ribbon_length([10, 1, 1]), 14);
+        assert_eq!(ribbon_length([1, 10, 1]), 14);
+    }
+
+    #[test]
+    fn test_ribbon_length_from_string() {
+        assert_eq!(ribbon_length_from_string("2x3x4"), Ok(34));
+        assert_eq!(ribbon_length_from_string("1x1x10"), Ok(14));
+        assert!(ribbon_length_from_string("1x1x").is_err());
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(super::Day02: cube_ish, long);
+}
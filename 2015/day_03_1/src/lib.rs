@@ -1,45 +1,72 @@
-use std::ops::AddAssign;
+use common::geometry::{Dir4, Point2};
 
 /// A direction on a 2D grid
-#[derive(Debug, Eq, PartialEq)]
-pub enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
+pub type Direction = Dir4;
 
 /// A position on a 2D grid
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
-pub struct Position {
-    pub x: i64,
-    pub y: i64,
+pub type Position = Point2<i64>;
+
+pub trait WorldMap {
+    fn take_step(&mut self, direction: Direction);
+    fn visited_positions(&self) -> usize;
+}
+
+pub struct SetBasedWorldMap {
+    current_position: Position,
+    visited: std::collections::HashSet<Position>,
 }
 
-impl TryFrom<char> for Direction {
-    type Error = ();
-    fn try_from(c: char) -> Result<Self, Self::Error> {
-        match c {
-            '^' => Ok(Self::North),
-            '>' => Ok(Self::East),
-            'v' => Ok(Self::South),
-            '<' => Ok(Self::West),
-            _ => Err(()),
+impl SetBasedWorldMap {
+    #[must_use]
+    pub fn new() -> Self {
+        let current_position = Position { x: 0, y: 0 };
+        Self {
+            current_position,
+            visited: vec![current_position].into_iter().collect(),
         }
     }
 }
 
-impl AddAssign<Direction> for Position {
-    fn add_assign(&mut self, direction: Direction) {
-        match direction {
-            Direction::North => self.y += 1,
-            Direction::East => self.x += 1,
-            Direction::South => self.y -= 1,
-            Direction::West => self.x -= 1,
-        }
+impl Default for SetBasedWorldMap {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
+impl WorldMap for SetBasedWorldMap {
+    fn take_step(&mut self, direction: Direction) {
+        self.current_position += direction;
+        self.visited.insert(self.current_position);
+    }
+
+    fn visited_positions(&self) -> usize {
+        self.visited.len()
+    }
+}
+
+pub fn positions_on_path(map: &mut impl WorldMap, path: impl Iterator<Item = Direction>) -> usize {
+    path.for_each(|direction| {
+        map.take_step(direction);
+    });
+    map.visited_positions()
+}
+
+/// Parses a path of directions, ignoring trailing whitespace.
+///
+/// # Errors
+///
+/// Returns an `Err` pointing at the first character that is not a direction.
+pub fn parse_directions(path: &str) -> common::Result<Vec<Direction>> {
+    Direction::arrows().parse_str(path.trim_end())
+}
+
+/// # Panics
+///
+/// Panics if the path contains a character that is not a direction.
+pub fn positions_on_path_chars(map: &mut impl WorldMap, path: impl Iterator<Item = char>) -> usize {
+    positions_on_path(map, path.map(Direction::try_from).map(Result::unwrap))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -50,7 +77,16 @@ mod tests {
         assert_eq!(Direction::try_from('>'), Ok(Direction::East));
         assert_eq!(Direction::try_from('v'), Ok(Direction::South));
         assert_eq!(Direction::try_from('<'), Ok(Direction::West));
-        assert_eq!(Direction::try_from('x'), Err(()));
+        assert!(Direction::try_from('x').is_err());
+    }
+
+    #[test]
+    fn test_parse_directions() {
+        assert_eq!(
+            parse_directions("^>\n"),
+            Ok(vec![Direction::North, Direction::East])
+        );
+        assert_eq!(parse_directions("^>x<").unwrap_err().column(), Some(3));
     }
 
     #[test]
@@ -58,10 +94,10 @@ mod tests {
         let mut position = Position { x: 0, y: 0 };
 
         position += Direction::North;
-        assert_eq!(position, Position { x: 0, y: 1 });
+        assert_eq!(position, Position { x: 0, y: -1 });
 
         position += Direction::East;
-        assert_eq!(position, Position { x: 1, y: 1 });
+        assert_eq!(position, Position { x: 1, y: -1 });
 
         position += Direction::South;
         assert_eq!(position, Position { x: 1, y: 0 });
@@ -69,4 +105,20 @@ mod tests {
         position += Direction::West;
         assert_eq!(position, Position { x: 0, y: 0 });
     }
+
+    #[test]
+    fn test_positions_on_path_string() {
+        assert_eq!(
+            positions_on_path_chars(&mut SetBasedWorldMap::new(), ">".chars()),
+            2
+        );
+        assert_eq!(
+            positions_on_path_chars(&mut SetBasedWorldMap::new(), "^>v<".chars()),
+            4
+        );
+        assert_eq!(
+            positions_on_path_chars(&mut SetBasedWorldMap::new(), "^v^v^v^v^v".chars()),
+            2
+        );
+    }
 }
@@ -0,0 +1,120 @@
+use common::generate::Rng;
+use common::solution::{Result, Solution};
+use day_03_1::{Direction, Position};
+
+trait WorldMap {
+    fn take_step(&mut self, current_position: &mut Position, direction: Direction);
+    fn visited_positions(&self) -> usize;
+}
+
+struct SetBasedWorldMap {
+    visited: std::collections::HashSet<Position>,
+}
+
+impl SetBasedWorldMap {
+    fn new() -> Self {
+        let current_position = Position { x: 0, y: 0 };
+        Self {
+            visited: vec![current_position].into_iter().collect(),
+        }
+    }
+}
+
+impl WorldMap for SetBasedWorldMap {
+    fn take_step(&mut self, current_position: &mut Position, direction: Direction) {
+        *current_position += direction;
+        self.visited.insert(*current_position);
+    }
+
+    fn visited_positions(&self) -> usize {
+        self.visited.len()
+    }
+}
+
+fn positions_on_path(map: &mut impl WorldMap, path: impl Iterator<Item = Direction>) -> usize {
+    const SANTA_COUNT: usize = 2;
+    path.scan(
+        (0, [Position { x: 0, y: 0 }; SANTA_COUNT]),
+        |(santa_id, positions), direction| {
+            map.take_step(&mut positions[*santa_id], direction);
+            *santa_id = (*santa_id + 1) % SANTA_COUNT;
+            Some(())
+        },
+    )
+    .last();
+    map.visited_positions()
+}
+
+#[cfg(test)]
+fn positions_on_path_chars(map: &mut impl WorldMap, path: impl Iterator<Item = char>) -> usize {
+    positions_on_path(
+        map,
+        path.map(Direction::try_from)
+            .map(std::result::Result::unwrap),
+    )
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+    const INPUT_SIZE: usize = 8192;
+
+    type Input = Vec<Direction>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Direction>> {
+        day_03_1::parse_directions(input)
+    }
+
+    fn part1(input: &Vec<Direction>) -> Result<usize> {
+        Ok(day_03_1::positions_on_path(
+            &mut day_03_1::SetBasedWorldMap::new(),
+            input.iter().copied(),
+        ))
+    }
+
+    fn part2(input: &Vec<Direction>) -> Result<usize> {
+        Ok(positions_on_path(
+            &mut SetBasedWorldMap::new(),
+            input.iter().copied(),
+        ))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(rng.string(size, b"^v<>"))
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions_on_path_string() {
+        assert_eq!(
+            positions_on_path_chars(&mut SetBasedWorldMap::new(), "^v".chars()),
+            3
+        );
+        assert_eq!(
+            positions_on_path_chars(&mut SetBasedWorldMap::new(), "^>v<".chars()),
+            3
+        );
+        assert_eq!(
+            positions_on_path_chars(&mut SetBasedWorldMap::new(), "^v^v^v^v^v".chars()),
+            11
+        );
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(super::Day03: single_move, square, back_and_forth, split_up);
+}
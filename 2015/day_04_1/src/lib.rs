@@ -0,0 +1,48 @@
+use common::Result;
+
+const LEADING_ZERO_NIBBLES: u32 = 5;
+fn valid_coin(hash: &[u8; 16]) -> bool {
+    u128::from_be_bytes(*hash).leading_zeros() >= LEADING_ZERO_NIBBLES * 4
+}
+
+#[must_use]
+pub fn smallest_suffix(secret_key: &str) -> Option<u64> {
+    #[allow(clippy::maybe_infinite_iter)]
+    (0u64..)
+        .map(|i| (i, format!("{secret_key}{i}")))
+        .map(|(i, s)| (i, md5::compute(s)))
+        .find(|(_, s)| valid_coin(s))
+        .map(|(i, _)| i)
+}
+
+/// The smallest suffix that mines an AdventCoin with the secret key of the puzzle input.
+///
+/// # Errors
+///
+/// Returns an `Err` if no suffix gives a valid coin.
+pub fn part1(input: &str) -> Result<u64> {
+    smallest_suffix(input.trim()).ok_or_else(|| "no valid coin found".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smallest_suffix_fast() {
+        assert_eq!(smallest_suffix("ffgimz"), Some(42));
+        assert_eq!(smallest_suffix("ccqsvy"), Some(1337));
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("ffgimz\n"), Ok(42));
+    }
+
+    #[test]
+    #[ignore = "Takes too long"]
+    fn test_smallest_suffix() {
+        assert_eq!(smallest_suffix("abcdef"), Some(609043));
+        assert_eq!(smallest_suffix("pqrstuv"), Some(1048970));
+    }
+}
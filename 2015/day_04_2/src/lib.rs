@@ -0,0 +1,123 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use common::generate::{Rng, LOWERCASE};
+use common::progress;
+use common::solution::{Result, Solution};
+use rayon::prelude::*;
+
+pub use day_04_1::part1;
+
+const LEADING_ZERO_NIBBLES: u32 = 6;
+fn valid_coin(hash: &[u8; 16]) -> bool {
+    u128::from_be_bytes(*hash).leading_zeros() >= LEADING_ZERO_NIBBLES * 4
+}
+
+/// The smallest suffix that gives a hash with six leading zeros, searching in parallel.
+///
+/// # Errors
+///
+/// Returns an `Err` if the search was cancelled or ran out of time.
+pub fn smallest_suffix(secret_key: &str) -> Result<Option<u64>> {
+    const CHUNK_SIZE: u64 = 1024;
+    let reporter = progress::current();
+    let tried = AtomicU64::new(0);
+    (u32::MIN..u32::MAX)
+        .into_par_iter()
+        .map(|chunk_id| {
+            let coin = (0u64..CHUNK_SIZE)
+                .map(|i| CHUNK_SIZE * u64::from(chunk_id) + i)
+                .map(|i| (i, format!("{secret_key}{i}")))
+                .map(|(i, s)| (i, md5::compute(s)))
+                .find(|(_, s)| valid_coin(s));
+            let tried = tried.fetch_add(CHUNK_SIZE, Ordering::Relaxed) + CHUNK_SIZE;
+            reporter.report("hashes", tried, None).map(|()| coin)
+        })
+        .find_first(|coin| !matches!(coin, Ok(None)))
+        .transpose()
+        .map(|coin| coin.flatten().map(|(i, _)| i))
+
+    // Actually I would like this to work:
+    // https://github.com/rayon-rs/rayon/issues/359
+    // https://github.com/rayon-rs/rayon/issues/520
+    // (u32::MIN..u32::MAX)
+    //     .into_par_iter()
+    //     .with_min_len(100_000)
+    //     .map(|i| (i, format!("{secret_key}{}")))i
+    //     .map(|(i, s)| (i, md5::compute(s)))
+    //     .find_first(|(_, s)| valid_coin(&**s))
+    //     .map(|(i, _)| i as u64)
+}
+
+/// The smallest suffix that mines an AdventCoin with six leading zeros with the secret key of the
+/// puzzle input.
+///
+/// # Errors
+///
+/// Returns an `Err` if no suffix gives a valid coin or the search was stopped.
+pub fn part2(input: &str) -> Result<u64> {
+    Day04::part2(&Day04::parse(input)?)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 4;
+    const INPUT_SIZE: usize = 8;
+
+    type Input = String;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(input: &String) -> Result<u64> {
+        day_04_1::part1(input)
+    }
+
+    fn part2(input: &String) -> Result<u64> {
+        smallest_suffix(input)?.ok_or_else(|| "no valid coin found".into())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(rng.string(size, LOWERCASE) + "\n")
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use common::progress::{CancelToken, Progress};
+
+    #[test]
+    fn test_smallest_suffix_fast() {
+        assert_eq!(smallest_suffix("bdmnoopw"), Ok(Some(42)));
+        assert_eq!(smallest_suffix("ccqsvy"), Ok(Some(1337)));
+    }
+    #[test]
+    fn test_cancel() {
+        let token = CancelToken::new();
+        let cancel = token.clone();
+        let start = Instant::now();
+        let canceller = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            cancel.cancel();
+        });
+        // the suffix for the real input is in the millions
+        let result = Progress::new()
+            .with_token(token)
+            .run(|| smallest_suffix("yzbqklnj"));
+        canceller.join().unwrap();
+        assert_eq!(result, Err("cancelled".into()));
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+}
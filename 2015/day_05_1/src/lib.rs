@@ -1,3 +1,5 @@
+use std::iter;
+
 /// Represents the result of a nicety check.
 #[derive(Debug)]
 pub enum CheckResult {
@@ -22,3 +24,85 @@ pub trait NicetyChecker {
     /// Consumes a token and returns the result of the nicety check.
     fn consume(&mut self, position: usize, token: Token) -> CheckResult;
 }
+
+/// A simple automaton for performing nicety checks on strings.
+/// Can calculate the nicety in a single pass.
+struct SimpleAutomaton {
+    vowels: u32,
+    last_letter: Option<char>,
+    double_letter: bool,
+}
+
+impl SimpleAutomaton {
+    fn new() -> Self {
+        Self {
+            vowels: 0,
+            last_letter: None,
+            double_letter: false,
+        }
+    }
+}
+
+impl NicetyChecker for SimpleAutomaton {
+    /// Checks if a string is nice according to the following rules:
+    /// - It contains at least three vowels (aeiou only)
+    /// - It contains at least one letter that appears twice in a row
+    /// - It does not contain the strings ab, cd, pq, or xy
+    fn consume(&mut self, _position: usize, token: Token) -> CheckResult {
+        match token {
+            Token::Char(c) => {
+                if matches!(c, 'a' | 'e' | 'i' | 'o' | 'u') {
+                    self.vowels += 1;
+                }
+                if self.last_letter == Some(c) {
+                    self.double_letter = true;
+                }
+
+                let last_letter = self.last_letter;
+                self.last_letter = Some(c);
+
+                if matches!(c, 'b' | 'd' | 'q' | 'y')
+                    && last_letter == Some(((c as u8) - 1) as char)
+                {
+                    CheckResult::Naughty
+                } else {
+                    CheckResult::Undecided
+                }
+            }
+            Token::End => {
+                if self.vowels >= 3 && self.double_letter {
+                    CheckResult::Nice
+                } else {
+                    CheckResult::Naughty
+                }
+            }
+        }
+    }
+}
+
+#[must_use]
+pub fn is_nice(chars: impl Iterator<Item = char>) -> bool {
+    chars
+        .map(Token::Char)
+        .chain(iter::once(Token::End))
+        .enumerate()
+        .scan(SimpleAutomaton::new(), |automaton, (i, t)| {
+            Some(automaton.consume(i, t))
+        })
+        .find(|r| !matches!(r, CheckResult::Undecided))
+        .map_or_else(|| false, |r| matches!(r, CheckResult::Nice))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_nice() {
+        assert!(is_nice("ugknbfddgicrmopn".chars()));
+        assert!(is_nice("aaa".chars()));
+        assert!(!is_nice("jchzalrnumimnmhp".chars()));
+        assert!(!is_nice("haegwjzuvuyypxyu".chars()));
+        assert!(!is_nice("dvszwmarrgswjxmb".chars()));
+    }
+}
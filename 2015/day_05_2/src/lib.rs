@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+use std::iter;
+
+use common::generate::{Rng, LOWERCASE};
+use common::solution::{Result, Solution};
+use day_05_1::{CheckResult, NicetyChecker, Token};
+
+/// A simple automaton for performing nicety checks on strings.
+/// Can calculate the nicety in a single pass.
+struct ComplicatedAutomaton {
+    last_letter: Option<char>,
+    before_last_letter: Option<char>,
+    pairs: BTreeMap<(char, char), usize>,
+    non_overlapping_repeated_pair: bool,
+    repeat_with_one_between: bool,
+}
+
+impl ComplicatedAutomaton {
+    fn new() -> Self {
+        Self {
+            last_letter: None,
+            before_last_letter: None,
+            pairs: BTreeMap::new(),
+            non_overlapping_repeated_pair: false,
+            repeat_with_one_between: false,
+        }
+    }
+}
+
+impl NicetyChecker for ComplicatedAutomaton {
+    /// Checks if a string is nice according to the following rules:
+    /// - It contains a pair of any two letters that appears at least twice without overlapping
+    /// - It contains at least one letter which repeats with exactly one letter between them
+    fn consume(&mut self, position: usize, token: Token) -> CheckResult {
+        match token {
+            Token::Char(c) => {
+                if !self.non_overlapping_repeated_pair {
+                    if let Some(last) = self.last_letter {
+                        self.pairs
+                            .entry((last, c))
+                            .and_modify(|other_position| {
+                                self.non_overlapping_repeated_pair = *other_position < position - 1;
+                            })
+                            .or_insert(position);
+                    }
+                }
+
+                self.repeat_with_one_between =
+                    self.repeat_with_one_between || self.before_last_letter == Some(c);
+
+                self.before_last_letter = self.last_letter;
+                self.last_letter = Some(c);
+
+                if self.non_overlapping_repeated_pair && self.repeat_with_one_between {
+                    CheckResult::Nice
+                } else {
+                    CheckResult::Undecided
+                }
+            }
+            Token::End => {
+                if self.non_overlapping_repeated_pair && self.repeat_with_one_between {
+                    CheckResult::Nice
+                } else {
+                    CheckResult::Naughty
+                }
+            }
+        }
+    }
+}
+
+fn is_nice(chars: impl Iterator<Item = char>) -> bool {
+    chars
+        .map(Token::Char)
+        .chain(iter::once(Token::End))
+        .enumerate()
+        .scan(ComplicatedAutomaton::new(), |automaton, (i, t)| {
+            Some(automaton.consume(i, t))
+        })
+        .find(|r| !matches!(r, CheckResult::Undecided))
+        .map_or_else(|| false, |r| matches!(r, CheckResult::Nice))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 5;
+    const INPUT_SIZE: usize = 1000;
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(input: &Vec<String>) -> Result<usize> {
+        Ok(input
+            .iter()
+            .filter(|s| day_05_1::is_nice(s.chars()))
+            .count())
+    }
+
+    fn part2(input: &Vec<String>) -> Result<usize> {
+        Ok(input.iter().filter(|s| is_nice(s.chars())).count())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(
+            (0..size)
+                .map(|_| rng.string(16, LOWERCASE) + "\n")
+                .collect(),
+        )
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_nice() {
+        assert!(is_nice("qjhvhtzxzqqjkmpb".chars()));
+        assert!(is_nice("xxyxx".chars()));
+        assert!(is_nice("xxxx".chars()));
+        assert!(!is_nice("uurcxstgmygtbstg".chars()));
+        assert!(!is_nice("ieodomkazucvgmuy".chars()));
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(super::Day05: old_rules, new_rules);
+}
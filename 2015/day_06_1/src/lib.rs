@@ -1,3 +1,5 @@
+use common::geometry::Point2;
+use common::Grid;
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -10,6 +12,7 @@ use nom::{
 
 /// Represents the possible actions that can be performed.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Action {
     TurnOn,
     TurnOff,
@@ -29,63 +32,160 @@ fn parse_action(input: &str) -> IResult<&str, Action> {
     }
 }
 
-impl<'a> TryFrom<&'a str> for Action {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
+impl TryFrom<&str> for Action {
+    type Error = common::Error;
 
-    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        parse_action(input).map(|(_, c)| c)
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        common::error::parse_complete(input, parse_action)
     }
 }
 
-/// Represents a coordinate with x and y values.
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Coordinate {
-    pub x: usize,
-    pub y: usize,
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TurnOn => write!(f, "turn on"),
+            Self::TurnOff => write!(f, "turn off"),
+            Self::Toggle => write!(f, "toggle"),
+        }
+    }
 }
 
+/// Represents a coordinate with x and y values.
+pub type Coordinate = Point2<usize>;
+
 fn parse_usize(input: &str) -> IResult<&str, usize> {
     map_res(recognize(digit1), str::parse)(input)
 }
 
 fn parse_coordinate(input: &str) -> IResult<&str, Coordinate> {
     let (input, (x, y)) = separated_pair(parse_usize, tag(","), parse_usize)(input)?;
-    Ok((input, Coordinate { x, y }))
-}
-
-impl<'a> TryFrom<&'a str> for Coordinate {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
-
-    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        parse_coordinate(input).map(|(_, c)| c)
-    }
+    Ok((input, Coordinate::new(x, y)))
 }
 
 /// Represents an instruction with an action, start coordinate, and end coordinate.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Instruction {
     pub action: Action,
     pub start: Coordinate,
     pub end: Coordinate,
 }
 
-impl<'a> TryFrom<&'a str> for Instruction {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    let (input, action) = parse_action(input)?;
+    let (input, _) = tag(" ")(input)?;
+    let (input, start) = parse_coordinate(input)?;
+    let (input, _) = tag(" through ")(input)?;
+    let (input, end) = parse_coordinate(input)?;
+
+    Ok((input, Instruction { action, start, end }))
+}
+
+impl Instruction {
+    /// Checks that both corners lie within a square grid of the given size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` naming the first corner that is out of range.
+    pub fn check_bounds(&self, size: usize) -> common::Result<()> {
+        match [self.start, self.end]
+            .into_iter()
+            .find(|corner| corner.x >= size || corner.y >= size)
+        {
+            Some(corner) => Err(common::Error::new(format!(
+                "coordinate {corner} is outside of the {size}x{size} grid"
+            ))
+            .with_text(self.to_string())),
+            None => Ok(()),
+        }
+    }
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = common::Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        common::error::parse_complete(input, parse_instruction)
+    }
+}
+
+impl std::fmt::Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} through {}", self.action, self.start, self.end)
+    }
+}
+
+const GRID_SIZE: usize = 1000;
+struct LightGrid {
+    lights: Grid<bool>,
+}
+
+impl LightGrid {
+    fn new() -> Self {
+        Self {
+            lights: Grid::new(GRID_SIZE, GRID_SIZE, false),
+        }
+    }
 
-    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        let (input, action) = parse_action(input)?;
-        let (input, _) = tag(" ")(input)?;
-        let (input, start) = parse_coordinate(input)?;
-        let (input, _) = tag(" through ")(input)?;
-        let (_, end) = parse_coordinate(input)?;
+    fn apply_instruction(&mut self, instruction: &Instruction) -> common::Result<()> {
+        instruction.check_bounds(GRID_SIZE)?;
+        self.lights
+            .region_mut(
+                instruction.start.x..=instruction.end.x,
+                instruction.start.y..=instruction.end.y,
+            )
+            .for_each(|(_, light)| match instruction.action {
+                Action::TurnOn => *light = true,
+                Action::TurnOff => *light = false,
+                Action::Toggle => *light = !*light,
+            });
+        Ok(())
+    }
 
-        Ok(Self { action, start, end })
+    fn count_lit_lights(&self) -> usize {
+        self.lights.values().filter(|&&light| light).count()
     }
 }
 
+/// Counts the lights that are lit after following all instructions.
+///
+/// # Errors
+///
+/// Returns an `Err` if an instruction reaches outside of the grid.
+pub fn count_lit_lights(
+    mut instructions: impl Iterator<Item = Instruction>,
+) -> common::Result<usize> {
+    let mut grid = LightGrid::new();
+    instructions.try_for_each(|instruction| grid.apply_instruction(&instruction))?;
+    Ok(grid.count_lit_lights())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        let action = prop_oneof![
+            Just(Action::TurnOn),
+            Just(Action::TurnOff),
+            Just(Action::Toggle),
+        ];
+        let coordinate = (any::<usize>(), any::<usize>()).prop_map(|(x, y)| Coordinate::new(x, y));
+        (action, coordinate.clone(), coordinate).prop_map(|(action, start, end)| Instruction {
+            action,
+            start,
+            end,
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_display_round_trip(instruction in instruction()) {
+            let printed = instruction.to_string();
+            prop_assert_eq!(Instruction::try_from(printed.as_str()), Ok(instruction));
+        }
+    }
 
     #[test]
     fn test_parse_action() {
@@ -113,4 +213,57 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_parse_instruction_invalid() {
+        let error = Instruction::try_from("turn on 0,0 thru 999,999").unwrap_err();
+        assert_eq!(error.column(), Some(12));
+        assert!(Instruction::try_from("turn on 0,0 through 999,999 and more").is_err());
+    }
+
+    #[test]
+    fn test_count_lit_lights() {
+        let mut instructions = vec![Instruction {
+            action: Action::TurnOn,
+            start: Coordinate { x: 0, y: 0 },
+            end: Coordinate { x: 999, y: 999 },
+        }];
+        assert_eq!(
+            count_lit_lights(instructions.clone().into_iter()),
+            Ok(1_000 * 1_000)
+        );
+
+        instructions.extend(vec![Instruction {
+            action: Action::Toggle,
+            start: Coordinate { x: 0, y: 0 },
+            end: Coordinate { x: 999, y: 0 },
+        }]);
+        assert_eq!(
+            count_lit_lights(instructions.clone().into_iter()),
+            Ok((1_000 - 1) * 1_000)
+        );
+
+        instructions.extend(vec![Instruction {
+            action: Action::TurnOff,
+            start: Coordinate { x: 499, y: 499 },
+            end: Coordinate { x: 500, y: 500 },
+        }]);
+        assert_eq!(
+            count_lit_lights(instructions.clone().into_iter()),
+            Ok((1_000 - 1) * 1_000 - 4)
+        );
+    }
+    #[test]
+    fn test_count_lit_lights_out_of_range() {
+        let instructions = vec![Instruction {
+            action: Action::TurnOn,
+            start: Coordinate { x: 0, y: 0 },
+            end: Coordinate { x: 1000, y: 1000 },
+        }];
+        let error = count_lit_lights(instructions.into_iter()).unwrap_err();
+        assert_eq!(
+            error.message(),
+            "coordinate 1000,1000 is outside of the 1000x1000 grid"
+        );
+    }
 }
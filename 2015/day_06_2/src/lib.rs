@@ -0,0 +1,146 @@
+use common::generate::Rng;
+use common::solution::{Result, Solution};
+use common::Grid;
+use day_06_1::{Action, Instruction};
+
+const GRID_SIZE: usize = 1000;
+struct LightGrid {
+    lights: Grid<usize>,
+}
+
+impl LightGrid {
+    fn new() -> Self {
+        Self {
+            lights: Grid::new(GRID_SIZE, GRID_SIZE, 0),
+        }
+    }
+
+    fn apply_instruction(&mut self, instruction: &Instruction) -> Result<()> {
+        instruction.check_bounds(GRID_SIZE)?;
+        self.lights
+            .region_mut(
+                instruction.start.x..=instruction.end.x,
+                instruction.start.y..=instruction.end.y,
+            )
+            .for_each(|(_, light)| match instruction.action {
+                Action::TurnOn => *light += 1,
+                Action::TurnOff => *light = light.saturating_sub(1),
+                Action::Toggle => *light += 2,
+            });
+        Ok(())
+    }
+
+    fn sum_brightness(&self) -> usize {
+        self.lights.values().sum()
+    }
+}
+
+fn sum_brightness(mut instructions: impl Iterator<Item = Instruction>) -> Result<usize> {
+    let mut grid = LightGrid::new();
+    instructions.try_for_each(|instruction| grid.apply_instruction(&instruction))?;
+    Ok(grid.sum_brightness())
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 6;
+    const INPUT_SIZE: usize = 300;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>> {
+        common::parse_lines(input)
+    }
+
+    fn part1(input: &Vec<Instruction>) -> Result<usize> {
+        day_06_1::count_lit_lights(input.iter().cloned())
+    }
+
+    fn part2(input: &Vec<Instruction>) -> Result<usize> {
+        sum_brightness(input.iter().cloned())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const ACTIONS: [&str; 3] = ["turn on", "turn off", "toggle"];
+
+        Some(
+            (0..size)
+                .map(|_| {
+                    let action = rng.choose(&ACTIONS);
+                    let [[x1, x2], [y1, y2]] = [(); 2].map(|()| {
+                        let mut span = [(); 2].map(|()| rng.range(0..=GRID_SIZE - 1));
+                        span.sort_unstable();
+                        span
+                    });
+                    format!("{action} {x1},{y1} through {x2},{y2}\n")
+                })
+                .collect(),
+        )
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use day_06_1::Coordinate;
+
+    #[test]
+    fn test_sum_brightness() {
+        let mut instructions = vec![Instruction {
+            action: Action::TurnOn,
+            start: Coordinate { x: 0, y: 0 },
+            end: Coordinate { x: 999, y: 999 },
+        }];
+        assert_eq!(
+            sum_brightness(instructions.clone().into_iter()),
+            Ok(1_000 * 1_000)
+        );
+
+        instructions.extend(vec![Instruction {
+            action: Action::Toggle,
+            start: Coordinate { x: 0, y: 0 },
+            end: Coordinate { x: 999, y: 0 },
+        }]);
+        assert_eq!(
+            sum_brightness(instructions.clone().into_iter()),
+            Ok(1_000 * 1_000 + 2_000)
+        );
+
+        instructions.extend(vec![Instruction {
+            action: Action::TurnOff,
+            start: Coordinate { x: 499, y: 499 },
+            end: Coordinate { x: 500, y: 500 },
+        }]);
+        assert_eq!(
+            sum_brightness(instructions.clone().into_iter()),
+            Ok(1_000 * 1_000 + 2_000 - 4)
+        );
+    }
+    #[test]
+    fn test_sum_brightness_out_of_range() {
+        let instructions = vec![Instruction {
+            action: Action::Toggle,
+            start: Coordinate { x: 1000, y: 0 },
+            end: Coordinate { x: 1000, y: 999 },
+        }];
+        let error = sum_brightness(instructions.into_iter()).unwrap_err();
+        assert_eq!(
+            error.message(),
+            "coordinate 1000,0 is outside of the 1000x1000 grid"
+        );
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(super::Day06: all_on, first_line, middle_off, single_light, toggle_all);
+}
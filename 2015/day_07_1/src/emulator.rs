@@ -66,10 +66,11 @@ fn try_evaluate_instruction(instruction: &Wire, signals: &SignalMap) -> Option<u
 ///
 /// # Errors
 ///
-/// Returns an error message if no progression is possible (dependency loop)
+/// Returns an error naming the remaining wires if no progression is possible (dependency loop or
+/// missing input)
 pub fn evaluate_network(
     instructions: impl Iterator<Item = Wire>,
-) -> Result<SignalMap, &'static str> {
+) -> Result<SignalMap, common::Error> {
     let mut signals = SignalMap {
         wires: HashMap::new(),
     };
@@ -84,7 +85,12 @@ pub fn evaluate_network(
                 continue 'outer;
             }
         }
-        return Err("No progress possible");
+        let mut names: Vec<_> = instructions.iter().map(|w| w.name.0.as_str()).collect();
+        names.sort_unstable();
+        return Err(common::Error::new(format!(
+            "no progress possible, cannot evaluate wires {}",
+            names.join(", ")
+        )));
     }
     Ok(signals)
 }
@@ -94,29 +100,19 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_evaluate() {
-        let signals = evaluate_network(
+    fn test_evaluate_loop() {
+        let error = evaluate_network(
             vec![
                 Wire::try_from("123 -> x").unwrap(),
-                Wire::try_from("456 -> y").unwrap(),
-                Wire::try_from("x AND y -> d").unwrap(),
-                Wire::try_from("x OR y -> e").unwrap(),
-                Wire::try_from("x LSHIFT 2 -> f").unwrap(),
-                Wire::try_from("y RSHIFT 2 -> g").unwrap(),
-                Wire::try_from("NOT x -> h").unwrap(),
-                Wire::try_from("NOT y -> i").unwrap(),
+                Wire::try_from("x AND b -> a").unwrap(),
+                Wire::try_from("a -> b").unwrap(),
             ]
             .into_iter(),
         )
-        .unwrap();
-
-        assert_eq!(signals.get_signal("d"), Some(72));
-        assert_eq!(signals.get_signal("e"), Some(507));
-        assert_eq!(signals.get_signal("f"), Some(492));
-        assert_eq!(signals.get_signal("g"), Some(114));
-        assert_eq!(signals.get_signal("h"), Some(65412));
-        assert_eq!(signals.get_signal("i"), Some(65079));
-        assert_eq!(signals.get_signal("x"), Some(123));
-        assert_eq!(signals.get_signal("y"), Some(456));
+        .unwrap_err();
+        assert_eq!(
+            error.message(),
+            "no progress possible, cannot evaluate wires a, b"
+        );
     }
 }
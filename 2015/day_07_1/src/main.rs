@@ -1,23 +1,17 @@
 //! Advent of code 2015 day 7 part 1
 
-use std::io::BufRead;
-
 use day_07_1::emulator::evaluate_network;
 use day_07_1::wire::Wire;
 
-fn main() {
-    let file = std::fs::File::open("input/input.txt").unwrap();
-    let reader = std::io::BufReader::new(file);
+fn main() -> common::Result<()> {
+    let input = common::input::load(env!("CARGO_MANIFEST_DIR"), 2015, 7)?;
 
-    let signals = evaluate_network(
-        reader
-            .lines()
-            .map(Result::unwrap)
-            .map(|line| Wire::try_from(line.as_ref()).unwrap()),
-    );
+    let wires: Vec<Wire> = common::parse_lines(&input)?;
+    let signals = evaluate_network(wires.into_iter())?;
 
     println!(
         "Signal on wire a: {}",
-        signals.unwrap().get_signal("a").unwrap()
+        signals.get_signal("a").ok_or("wire a carries no signal")?
     );
+    Ok(())
 }
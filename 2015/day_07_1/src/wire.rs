@@ -1,13 +1,16 @@
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Name(pub String);
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Signal {
     Immediate(u16),
     Connection(Name),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Gate {
     Not(Signal),
     And(Signal, Signal),
@@ -17,6 +20,7 @@ pub enum Gate {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Source {
     Value(u16),
     Gate(Gate),
@@ -24,6 +28,7 @@ pub enum Source {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Wire {
     pub source: Source,
     pub name: Name,
@@ -105,41 +110,130 @@ mod parsers {
     }
 }
 
-impl<'a> TryFrom<&'a str> for Signal {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
+impl TryFrom<&str> for Signal {
+    type Error = common::Error;
 
-    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        parsers::signal(input).map(|(_, c)| c)
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        common::error::parse_complete(input, parsers::signal)
     }
 }
 
-impl<'a> TryFrom<&'a str> for Gate {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
+impl TryFrom<&str> for Gate {
+    type Error = common::Error;
 
-    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        parsers::gate(input).map(|(_, c)| c)
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        common::error::parse_complete(input, parsers::gate)
     }
 }
 
-impl<'a> TryFrom<&'a str> for Source {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
+impl TryFrom<&str> for Source {
+    type Error = common::Error;
 
-    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        parsers::source(input).map(|(_, c)| c)
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        common::error::parse_complete(input, parsers::source)
     }
 }
 
-impl<'a> TryFrom<&'a str> for Wire {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
+impl TryFrom<&str> for Wire {
+    type Error = common::Error;
 
-    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        parsers::wire(input).map(|(_, c)| c)
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        common::error::parse_complete(input, parsers::wire)
+    }
+}
+
+impl std::fmt::Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::fmt::Display for Signal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Immediate(value) => write!(f, "{value}"),
+            Self::Connection(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl std::fmt::Display for Gate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Not(signal) => write!(f, "NOT {signal}"),
+            Self::And(lhs, rhs) => write!(f, "{lhs} AND {rhs}"),
+            Self::Or(lhs, rhs) => write!(f, "{lhs} OR {rhs}"),
+            Self::LShift(lhs, rhs) => write!(f, "{lhs} LSHIFT {rhs}"),
+            Self::RShift(lhs, rhs) => write!(f, "{lhs} RSHIFT {rhs}"),
+        }
+    }
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Value(value) => write!(f, "{value}"),
+            Self::Gate(gate) => write!(f, "{gate}"),
+            Self::Direct(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl std::fmt::Display for Wire {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {}", self.source, self.name)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+
+    fn name() -> impl Strategy<Value = Name> {
+        "[a-z]{1,2}".prop_map(Name)
+    }
+
+    fn signal() -> impl Strategy<Value = Signal> {
+        prop_oneof![
+            any::<u16>().prop_map(Signal::Immediate),
+            name().prop_map(Signal::Connection),
+        ]
+    }
+
+    fn gate() -> impl Strategy<Value = Gate> {
+        prop_oneof![
+            signal().prop_map(Gate::Not),
+            (signal(), signal()).prop_map(|(lhs, rhs)| Gate::And(lhs, rhs)),
+            (signal(), signal()).prop_map(|(lhs, rhs)| Gate::Or(lhs, rhs)),
+            (signal(), any::<u16>()).prop_map(|(lhs, rhs)| Gate::LShift(lhs, rhs)),
+            (signal(), any::<u16>()).prop_map(|(lhs, rhs)| Gate::RShift(lhs, rhs)),
+        ]
+    }
+
+    fn wire() -> impl Strategy<Value = Wire> {
+        let source = prop_oneof![
+            any::<u16>().prop_map(Source::Value),
+            gate().prop_map(Source::Gate),
+            name().prop_map(Source::Direct),
+        ];
+        (source, name()).prop_map(|(source, name)| Wire { source, name })
+    }
+
+    proptest! {
+        #[test]
+        fn test_display_round_trip(wire in wire()) {
+            let printed = wire.to_string();
+            prop_assert_eq!(Wire::try_from(printed.as_str()), Ok(wire));
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn test_json_round_trip(wire in wire()) {
+            let json = serde_json::to_string(&wire).unwrap();
+            prop_assert_eq!(serde_json::from_str::<Wire>(&json).unwrap(), wire);
+        }
+    }
 
     #[test]
     fn test_signal() {
@@ -329,4 +423,18 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_display() {
+        let wire = Wire::try_from("x LSHIFT 2 -> z").unwrap();
+        assert_eq!(wire.to_string(), "x LSHIFT 2 -> z");
+        assert_eq!(Gate::try_from("NOT 123").unwrap().to_string(), "NOT 123");
+    }
+
+    #[test]
+    fn test_parse_wire_invalid() {
+        let error = Wire::try_from("x XOR y -> z").unwrap_err();
+        assert_eq!(error.column(), Some(2));
+        assert!(Wire::try_from("123 -> x y").is_err());
+    }
 }
@@ -0,0 +1,153 @@
+use common::generate::Rng;
+use common::solution::{Result, Solution};
+use day_07_1::emulator::evaluate_network;
+use day_07_1::wire::{Name, Source, Wire};
+
+fn signal_on_wire_a(wires: impl Iterator<Item = Wire>) -> Result<u16> {
+    evaluate_network(wires)?
+        .get_signal("a")
+        .ok_or_else(|| "wire a carries no signal".into())
+}
+
+/// Replaces the source of wire b with the given value.
+fn override_wire_b(wires: &[Wire], value: u16) -> impl Iterator<Item = Wire> + '_ {
+    let b = Name("b".to_string());
+    wires.iter().map(move |wire| {
+        if wire.name == b {
+            Wire {
+                source: Source::Value(value),
+                name: wire.name.clone(),
+            }
+        } else {
+            wire.clone()
+        }
+    })
+}
+
+/// The name of the `n`th wire: `a` to `z`, then `aa`, `ab` and so on.
+fn wire_name(mut n: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'a' + u8::try_from(n % 26).expect("below 26"));
+        if n < 26 {
+            break;
+        }
+        n = n / 26 - 1;
+    }
+    name.reverse();
+    String::from_utf8(name).expect("only ASCII letters")
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 7;
+    const INPUT_SIZE: usize = 339;
+
+    type Input = Vec<Wire>;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    fn parse(input: &str) -> Result<Vec<Wire>> {
+        common::parse_lines(input)
+    }
+
+    fn part1(input: &Vec<Wire>) -> Result<u16> {
+        signal_on_wire_a(input.iter().cloned())
+    }
+
+    fn part2(input: &Vec<Wire>) -> Result<u16> {
+        let wire_a_part_1 = signal_on_wire_a(input.iter().cloned())?;
+        signal_on_wire_a(override_wire_b(input, wire_a_part_1))
+    }
+
+    /// Generates a circuit of `size` wires in random order, where every gate only reads wires
+    /// defined before it, so that there are no loops.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(2);
+        let mut wires = vec![format!("{} -> b", rng.range(0..=u16::MAX))];
+        let mut names = vec!["b".to_string()];
+        for i in 0..size.saturating_sub(1) {
+            // `a` is the last wire, everything else is named like a column of a spreadsheet
+            let name = if i + 2 == size {
+                "a".to_string()
+            } else {
+                wire_name(i + 2)
+            };
+            let signal = |rng: &mut Rng| {
+                if rng.ratio(1, 10) {
+                    rng.range(0..=u16::MAX).to_string()
+                } else {
+                    rng.choose(&names).clone()
+                }
+            };
+            let source = match rng.range(0..=9) {
+                0 => rng.range(0..=u16::MAX).to_string(),
+                1 => rng.choose(&names).clone(),
+                2 => format!("NOT {}", signal(rng)),
+                3 | 4 => format!("{} AND {}", signal(rng), signal(rng)),
+                5 | 6 => format!("{} OR {}", signal(rng), signal(rng)),
+                7 => format!("{} LSHIFT {}", signal(rng), rng.range(1..=15)),
+                _ => format!("{} RSHIFT {}", signal(rng), rng.range(1..=15)),
+            };
+            wires.push(format!("{source} -> {name}"));
+            names.push(name);
+        }
+        rng.shuffle(&mut wires);
+        Some(wires.iter().map(|wire| format!("{wire}\n")).collect())
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_override_wire_b() {
+        let wires = Day07::parse("123 -> x\nx -> b\nb -> a").unwrap();
+        assert_eq!(Day07::part1(&wires).unwrap(), 123);
+
+        let wires: Vec<_> = override_wire_b(&wires, 42).collect();
+        assert_eq!(wires[1], Wire::try_from("42 -> b").unwrap());
+        assert_eq!(signal_on_wire_a(wires.into_iter()).unwrap(), 42);
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(wire_name(0), "a");
+        assert_eq!(wire_name(25), "z");
+        assert_eq!(wire_name(27), "ab");
+
+        for seed in 0..10 {
+            let input = Day07::generate(&mut Rng::new(seed), 50).unwrap();
+            let wires = Day07::parse(&input).unwrap();
+            assert_eq!(wires.len(), 50);
+            assert!(Day07::part1(&wires).is_ok());
+            assert!(Day07::part2(&wires).is_ok());
+        }
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use common::solution::Result;
+    use day_07_1::emulator::evaluate_network;
+    use day_07_1::wire::Wire;
+
+    // takes the `Input` of `Day07` like the parts
+    #[allow(clippy::ptr_arg)]
+    fn signal(wires: &Vec<Wire>, wire: &str) -> Result<String> {
+        evaluate_network(wires.iter().cloned())?
+            .get_signal(wire)
+            .map(|signal| signal.to_string())
+            .ok_or_else(|| format!("wire {wire} carries no signal").into())
+    }
+
+    common::example_tests!(super::Day07, signal: some_gates);
+}
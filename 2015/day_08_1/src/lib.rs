@@ -0,0 +1,152 @@
+use common::{Error, Result};
+
+enum State {
+    Start,
+    Valid,
+    EscapeSymbol,
+    EscapeHexX,
+    EscapeHex1,
+    End,
+    Error,
+}
+
+/// Counts the characters of a string literal in code and in memory, one character at a time.
+pub struct CharacterCounter {
+    /// the number of characters in the raw input string
+    code: usize,
+
+    /// the number of characters in memory after parsing
+    data: usize,
+
+    state: State,
+}
+
+impl Default for CharacterCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CharacterCounter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            code: 0,
+            data: 0,
+            state: State::Start,
+        }
+    }
+
+    pub fn process(&mut self, input: char) {
+        self.code += 1;
+        self.state = match (&self.state, input) {
+            (State::Start, '"') => State::Valid,
+            (State::Valid, '"') => State::End,
+            (State::Valid, '\\') => State::EscapeSymbol,
+            (State::Valid, _) | (State::EscapeSymbol, '\\' | '"') => {
+                self.data += 1;
+                State::Valid
+            }
+            (State::EscapeSymbol, 'x') => State::EscapeHexX,
+            (State::EscapeHexX, c) if c.is_ascii_hexdigit() => State::EscapeHex1,
+            (State::EscapeHex1, c) if c.is_ascii_hexdigit() => {
+                self.data += 1;
+                State::Valid
+            }
+            (_, _) => State::Error,
+        }
+    }
+
+    /// The sizes in code and in memory, or `None` if the literal is malformed or unterminated.
+    #[must_use]
+    pub fn count(&self) -> Option<(usize, usize)> {
+        if !matches!(self.state, State::End) {
+            return None;
+        }
+        Some((self.code, self.data))
+    }
+}
+
+#[must_use]
+pub fn count_characters(input: &str) -> Option<(usize, usize)> {
+    let mut counter = CharacterCounter::new();
+    input.chars().for_each(|c| counter.process(c));
+    counter.count()
+}
+
+/// Sums up the code and data sizes of all strings.
+pub fn total_size(counts: impl Iterator<Item = (usize, usize)>) -> (usize, usize) {
+    counts.fold((0, 0), |(code_acc, data_acc), (code, data)| {
+        (code_acc + code, data_acc + data)
+    })
+}
+
+/// The number of characters of code minus those in memory, summed over all string literals.
+///
+/// # Errors
+///
+/// Returns an `Err` pointing at the first literal that is malformed.
+pub fn size_difference<'a>(literals: impl Iterator<Item = &'a str>) -> Result<usize> {
+    let counts = literals
+        .enumerate()
+        .map(|(i, s)| {
+            count_characters(s).ok_or_else(|| {
+                Error::new("invalid string literal")
+                    .with_line(i + 1)
+                    .with_text(s)
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let (code, data) = total_size(counts.into_iter());
+    Ok(code - data)
+}
+
+/// The size difference of the string literals of the puzzle input, one per line.
+///
+/// # Errors
+///
+/// Returns an `Err` pointing at the first literal that is malformed.
+pub fn part1(input: &str) -> Result<usize> {
+    size_difference(input.lines())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_terminated_string() {
+        assert_eq!(count_characters(r#""abc"#), None);
+    }
+
+    #[test]
+    fn test_not_started_string() {
+        assert_eq!(count_characters(r#"abc""#), None);
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(count_characters(r#""""#), Some((2, 0)));
+    }
+
+    #[test]
+    fn test_abc_string() {
+        assert_eq!(count_characters(r#""abc""#), Some((5, 3)));
+    }
+
+    #[test]
+    fn test_aaaaaa_string() {
+        assert_eq!(count_characters(r#""aaa\"aaa""#), Some((10, 7)));
+    }
+
+    #[test]
+    fn test_hex_string() {
+        assert_eq!(count_characters(r#""\x27""#), Some((6, 1)));
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("\"\"\n\"abc\"\n"), Ok(4));
+        assert_eq!(part1("\"\"\n\"abc\n").unwrap_err().line(), Some(2));
+    }
+}
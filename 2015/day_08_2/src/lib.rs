@@ -0,0 +1,134 @@
+use common::generate::{Rng, LOWERCASE};
+use common::solution::{Result, Solution};
+pub use day_08_1::part1;
+use day_08_1::total_size;
+
+/// Counts the characters of a string in memory and after encoding it as a string literal, one
+/// character at a time.
+pub struct CharacterCounter {
+    /// the number of characters in the raw input string
+    code: usize,
+
+    /// the number of characters in memory after parsing
+    data: usize,
+}
+
+impl Default for CharacterCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CharacterCounter {
+    #[must_use]
+    pub fn new() -> Self {
+        // the surrounding quotes
+        Self { code: 2, data: 0 }
+    }
+
+    pub fn process(&mut self, input: char) {
+        self.data += 1;
+        self.code += 1;
+        match input {
+            '\\' | '"' => self.code += 1,
+            _ => {}
+        }
+    }
+
+    /// The sizes of the encoded literal and of the string itself.
+    #[must_use]
+    pub fn count(&self) -> (usize, usize) {
+        (self.code, self.data)
+    }
+}
+
+#[must_use]
+pub fn count_characters(input: &str) -> (usize, usize) {
+    let mut counter = CharacterCounter::new();
+    input.chars().for_each(|c| counter.process(c));
+    counter.count()
+}
+
+/// The number of characters needed to encode the strings of the puzzle input as literals, minus
+/// the characters of the strings, summed over all lines.
+#[must_use]
+pub fn part2(input: &str) -> usize {
+    let (code, data) = total_size(input.lines().map(count_characters));
+    code - data
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 8;
+    const INPUT_SIZE: usize = 300;
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(input: &Vec<String>) -> Result<usize> {
+        day_08_1::size_difference(input.iter().map(String::as_str))
+    }
+
+    fn part2(input: &Vec<String>) -> Result<usize> {
+        let (code, data) = total_size(input.iter().map(|s| count_characters(s)));
+        Ok(code - data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let literal = |rng: &mut Rng| {
+            let mut literal = String::from("\"");
+            for _ in 0..rng.range(0..=30) {
+                match rng.range(0..=9) {
+                    0 => literal.push_str("\\\\"),
+                    1 => literal.push_str("\\\""),
+                    2 => literal.push_str(&format!("\\x{:02x}", rng.range(0..=u8::MAX))),
+                    _ => literal.push(char::from(*rng.choose(LOWERCASE))),
+                }
+            }
+            literal + "\"\n"
+        };
+        Some((0..size).map(|_| literal(rng)).collect())
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(count_characters(r#""""#), (6, 2));
+    }
+
+    #[test]
+    fn test_abc_string() {
+        assert_eq!(count_characters(r#""abc""#), (9, 5));
+    }
+
+    #[test]
+    fn test_aaaaaa_string() {
+        assert_eq!(count_characters(r#""aaa\"aaa""#), (16, 10));
+    }
+
+    #[test]
+    fn test_hex_string() {
+        assert_eq!(count_characters(r#""\x27""#), (11, 6));
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(super::Day08: literals);
+}
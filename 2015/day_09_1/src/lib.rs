@@ -2,6 +2,7 @@ use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LocationPair {
     from: String,
     to: String,
@@ -38,11 +39,17 @@ mod parsers {
     }
 }
 
-impl<'a> TryFrom<&'a str> for LocationPair {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
+impl TryFrom<&str> for LocationPair {
+    type Error = common::Error;
 
-    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        parsers::location_pair(input).map(|(_, c)| c)
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        common::error::parse_complete(input, parsers::location_pair)
+    }
+}
+
+impl std::fmt::Display for LocationPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} to {} = {}", self.from, self.to, self.distance)
     }
 }
 
@@ -117,6 +124,20 @@ pub fn solve_traveling_salesman(matrix: &[Vec<u64>]) -> Option<(u64, Vec<usize>)
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_display_round_trip(
+            from in "[A-Z][a-z]{0,9}",
+            to in "[A-Z][a-z]{0,9}",
+            distance in any::<u64>(),
+        ) {
+            let pair = LocationPair { from, to, distance };
+            let printed = pair.to_string();
+            prop_assert_eq!(LocationPair::try_from(printed.as_str()), Ok(pair));
+        }
+    }
 
     fn get_test_locations() -> Vec<LocationPair> {
         vec![
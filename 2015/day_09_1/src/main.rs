@@ -1,24 +1,18 @@
 //! Advent of code 2015 day 9 part 1
 
 use day_09_1::{build_distance_matrix, build_index, solve_traveling_salesman, LocationPair};
-use std::io::BufRead;
+fn main() -> common::Result<()> {
+    let input = common::input::load(env!("CARGO_MANIFEST_DIR"), 2015, 9)?;
 
-fn main() {
-    let file = std::fs::File::open("input/input.txt").unwrap();
-    let reader = std::io::BufReader::new(file);
-
-    let locations: Vec<LocationPair> = reader
-        .lines()
-        .map(Result::unwrap)
-        .map(|s| LocationPair::try_from(&*s).unwrap())
-        .collect();
+    let locations: Vec<LocationPair> = common::parse_lines(&input)?;
 
     let (index_to_city, city_to_index) = build_index(&locations);
     let matrix = build_distance_matrix(&locations, &city_to_index);
 
-    let (distance, path) = solve_traveling_salesman(&matrix).unwrap();
+    let (distance, path) = solve_traveling_salesman(&matrix).ok_or("no route found")?;
     println!("Shortest distance: {distance}");
 
     let path: Vec<_> = path.iter().map(|i| index_to_city[*i].clone()).collect();
     println!("Path: {path:?}");
+    Ok(())
 }
@@ -0,0 +1,81 @@
+use common::generate::Rng;
+use common::solution::{Answer, Result, Solution, Work};
+use day_09_1::{
+    build_distance_matrix, build_index, solve_traveling_salesman_with_target, LocationPair, Target,
+};
+
+fn route_distance(locations: &[LocationPair], target: &Target) -> Option<Answer<u64>> {
+    let (index_to_city, city_to_index) = build_index(locations);
+    let matrix = build_distance_matrix(locations, &city_to_index);
+    solve_traveling_salesman_with_target(&matrix, target).map(|(distance, path)| {
+        let route = path.iter().map(|&city| &index_to_city[city]);
+        Answer::new(distance).with_work(Work::steps("route", route))
+    })
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 9;
+    const INPUT_SIZE: usize = 8;
+
+    type Input = Vec<LocationPair>;
+    type Answer1 = Answer<u64>;
+    type Answer2 = Answer<u64>;
+
+    fn parse(input: &str) -> Result<Vec<LocationPair>> {
+        common::parse_lines(input)
+    }
+
+    fn part1(input: &Vec<LocationPair>) -> Result<Answer<u64>> {
+        route_distance(input, &Target::Min).ok_or_else(|| "no route found".into())
+    }
+
+    fn part2(input: &Vec<LocationPair>) -> Result<Answer<u64>> {
+        route_distance(input, &Target::Max).ok_or_else(|| "no route found".into())
+    }
+
+    /// Generates the distances between every pair of `size` cities.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let cities = rng.names(size);
+        let mut input = String::new();
+        for (i, from) in cities.iter().enumerate() {
+            for to in &cities[i + 1..] {
+                input.push_str(&format!("{from} to {to} = {}\n", rng.range(10..=150)));
+            }
+        }
+        Some(input)
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_distance() {
+        let locations = Day09::parse(
+            "London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141",
+        )
+        .unwrap();
+        let shortest = Day09::part1(&locations).unwrap();
+        assert_eq!(shortest.value, 605);
+        let route = shortest.work.unwrap().to_string();
+        assert!(
+            route == "route: London -> Dublin -> Belfast"
+                || route == "route: Belfast -> Dublin -> London"
+        );
+        assert_eq!(Day09::part2(&locations).unwrap().value, 982);
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(super::Day09: three_cities);
+}
@@ -2,8 +2,9 @@
 
 use day_10_1::repeat_look_and_say;
 
-fn main() {
-    let input = "1113222113";
-    let size = repeat_look_and_say(input, 40).len();
+fn main() -> common::Result<()> {
+    let input = common::input::load(env!("CARGO_MANIFEST_DIR"), 2015, 10)?;
+    let size = repeat_look_and_say(input.trim(), 40).len();
     print!("Final length: {size}");
+    Ok(())
 }
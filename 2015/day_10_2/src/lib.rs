@@ -0,0 +1,40 @@
+use common::generate::Rng;
+use common::solution::{Result, Solution};
+use day_10_1::repeat_look_and_say;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 10;
+    const INPUT_SIZE: usize = 10;
+
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<String> {
+        let input = input.trim();
+        if input.is_empty() || !input.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("not a sequence of digits: {input}").into());
+        }
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &String) -> Result<usize> {
+        Ok(repeat_look_and_say(input, 40).len())
+    }
+
+    fn part2(input: &String) -> Result<usize> {
+        Ok(repeat_look_and_say(input, 50).len())
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(rng.string(size.max(1), b"123") + "\n")
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
@@ -2,7 +2,9 @@
 
 use day_11_1::password_generator::next_valid_password_str;
 
-fn main() {
-    let next_password = next_valid_password_str("hxbxwxba");
+fn main() -> common::Result<()> {
+    let input = common::input::load(env!("CARGO_MANIFEST_DIR"), 2015, 11)?;
+    let next_password = next_valid_password_str(input.trim());
     println!("Next valid password: {next_password}");
+    Ok(())
 }
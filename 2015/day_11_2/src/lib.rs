@@ -0,0 +1,48 @@
+use common::generate::Rng;
+use common::solution::{Result, Solution};
+use day_11_1::password_generator::{next_password_str, next_valid_password_str};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 11;
+    const INPUT_SIZE: usize = 8;
+
+    type Input = String;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<String> {
+        let input = input.trim();
+        if input.is_empty() || !input.chars().all(|c| c.is_ascii_lowercase()) {
+            return Err(format!("not a password of lowercase letters: {input}").into());
+        }
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &String) -> Result<String> {
+        Ok(next_valid_password_str(input))
+    }
+
+    fn part2(input: &String) -> Result<String> {
+        Ok(next_valid_password_str(&next_password_str(
+            &next_valid_password_str(input),
+        )))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // a valid password needs at least two pairs and a straight of three letters
+        Some(rng.string(size.max(5), b"abcdefghjkmnpqrstuvwxyz") + "\n")
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(super::Day11: straight_first, forbidden_letter);
+}
@@ -0,0 +1,226 @@
+use common::{Error, Result};
+
+/// Iterates over the numbers in the characters of a JSON document.
+///
+/// Characters inside of strings are skipped. Like for `serde_json` in part 2, numbers that are not
+/// integers in the range of `i64` are yielded as an `Err`.
+pub fn extract_numbers(chars: impl Iterator<Item = char>) -> impl Iterator<Item = Result<i64>> {
+    // `None` once the number overflowed
+    let mut number = Some(0_i64);
+    let mut is_negative = false;
+    let mut is_number = false;
+    let mut is_string = false;
+    let mut is_escaped = false;
+    // set after a fraction or exponent until the rest of the number is skipped
+    let mut is_rejected = false;
+
+    // the trailing `None` ends a number at the very end of the input
+    chars
+        .map(Some)
+        .chain(std::iter::once(None))
+        .filter_map(move |c: Option<char>| {
+            if is_string {
+                if is_escaped {
+                    is_escaped = false;
+                } else if c == Some('\\') {
+                    is_escaped = true;
+                } else if c == Some('"') {
+                    is_string = false;
+                }
+                return None;
+            }
+            if is_rejected {
+                if matches!(c, Some('0'..='9' | '.' | 'e' | 'E' | '+' | '-')) {
+                    return None;
+                }
+                is_rejected = false;
+            }
+
+            match c {
+                Some('-') => {
+                    is_negative = true;
+                    None
+                }
+                Some(c @ '0'..='9') => {
+                    is_number = true;
+                    let digit = i64::from(c.to_digit(10).unwrap());
+                    number = number.and_then(|number| {
+                        let number = number.checked_mul(10)?;
+                        if is_negative {
+                            number.checked_sub(digit)
+                        } else {
+                            number.checked_add(digit)
+                        }
+                    });
+                    None
+                }
+                c => {
+                    is_string = c == Some('"');
+                    let result = if !is_number {
+                        None
+                    } else if matches!(c, Some('.' | 'e' | 'E')) {
+                        is_rejected = true;
+                        Some(Err(Error::new("not an integer")))
+                    } else {
+                        Some(number.ok_or_else(|| Error::new("number out of range")))
+                    };
+                    number = Some(0);
+                    is_negative = false;
+                    is_number = false;
+                    result
+                }
+            }
+        })
+}
+
+/// Checks that the puzzle input is ASCII and returns it as a document.
+///
+/// # Errors
+///
+/// Returns an `Err` if the input is not ASCII.
+pub fn parse(input: &str) -> Result<String> {
+    Ok(input
+        .bytes()
+        .map(common::ascii_byte_to_char)
+        .collect::<std::result::Result<_, _>>()?)
+}
+
+/// Sums up numbers, failing on the first `Err` or if the sum overflows.
+///
+/// # Errors
+///
+/// Returns the first `Err` of the numbers, or an `Err` if the sum is out of the range of `i64`.
+pub fn checked_sum(mut numbers: impl Iterator<Item = Result<i64>>) -> Result<i64> {
+    numbers.try_fold(0_i64, |sum, number| {
+        sum.checked_add(number?)
+            .ok_or_else(|| Error::new("sum out of range"))
+    })
+}
+
+/// The sum of all numbers in a JSON document.
+///
+/// # Errors
+///
+/// Returns an `Err` if a number is not an integer or a number or the sum is out of the range of
+/// `i64`.
+pub fn sum_numbers(document: &str) -> Result<i64> {
+    checked_sum(extract_numbers(document.chars()))
+}
+
+/// The sum of all numbers in the JSON document of the puzzle input.
+///
+/// # Errors
+///
+/// Returns an `Err` if the input is not ASCII, contains a number that is not an integer, or if a
+/// number or the sum is out of the range of `i64`.
+pub fn part1(input: &str) -> Result<i64> {
+    sum_numbers(&parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_array() {
+        let numbers: Vec<_> = extract_numbers("[1,2,3]".chars())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(numbers, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_object() {
+        let numbers: Vec<_> = extract_numbers(r#"{"a":2,"b":4}"#.chars())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(numbers, vec![2, 4]);
+    }
+
+    #[test]
+    fn test_nested_list() {
+        let numbers: Vec<_> = extract_numbers("[[[3]]]".chars())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(numbers, vec![3]);
+    }
+
+    #[test]
+    fn test_nested_object() {
+        let numbers: Vec<_> = extract_numbers(r#"{"a":{"b":4},"c":-1}"#.chars())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(numbers, vec![4, -1]);
+    }
+
+    #[test]
+    fn test_array_in_object() {
+        let numbers: Vec<_> = extract_numbers(r#"{"a":[-1,1]}"#.chars())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(numbers, vec![-1, 1]);
+    }
+
+    #[test]
+    fn test_object_in_array() {
+        let numbers: Vec<_> = extract_numbers(r#"[-1,{"a":1}]"#.chars())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(numbers, vec![-1, 1]);
+    }
+
+    #[test]
+    fn test_empty_array() {
+        let numbers: Vec<_> = extract_numbers(r#"[]"#.chars())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(numbers, Vec::<i64>::new());
+    }
+
+    #[test]
+    fn test_empty_object() {
+        let numbers: Vec<_> = extract_numbers(r#"{}"#.chars())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(numbers, Vec::<i64>::new());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(r#"[-1,{"a":1}]"#), Ok(0));
+    }
+
+    #[test]
+    fn test_trailing_number() {
+        assert_eq!(part1("5"), Ok(5));
+        assert_eq!(part1("-5"), Ok(-5));
+        assert_eq!(part1("[1,2]5"), Ok(8));
+    }
+
+    #[test]
+    fn test_strings() {
+        assert_eq!(part1(r#"["a1",2]"#), Ok(2));
+        assert_eq!(part1(r#"["\"3",4]"#), Ok(4));
+        assert_eq!(part1(r#"{"5":6}"#), Ok(6));
+    }
+
+    #[test]
+    fn test_non_integer() {
+        for document in ["[1,1.5]", "[1e5]", "[2E-3]", "1.0"] {
+            assert!(part1(document).is_err(), "{document}");
+        }
+        let numbers: Vec<_> = extract_numbers("[1.5,2]".chars()).collect();
+        assert_eq!(numbers.len(), 2);
+        assert!(numbers[0].is_err());
+        assert_eq!(numbers[1], Ok(2));
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(part1("[9223372036854775807]"), Ok(i64::MAX));
+        assert!(part1("[9223372036854775808]").is_err());
+        assert!(part1("[9223372036854775807,1]").is_err());
+        assert!(part1("[-9223372036854775807,-2]").is_err());
+        assert_eq!(part1("[-9223372036854775808]"), Ok(i64::MIN));
+    }
+}
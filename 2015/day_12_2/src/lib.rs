@@ -0,0 +1,180 @@
+use common::generate::Rng;
+use common::solution::{Error, Result, Solution};
+pub use day_12_1::part1;
+use serde_json::Value;
+
+/// Iterates over the numbers of a JSON document, skipping objects with a value of `"red"`.
+///
+/// Numbers that are not integers in the range of `i64` are yielded as an `Err`.
+#[must_use]
+pub fn extract_non_red_numbers<'a>(root: &'a Value) -> Box<dyn Iterator<Item = Result<i64>> + 'a> {
+    if let Value::Array(array) = root {
+        Box::new(array.iter().flat_map(extract_non_red_numbers))
+    } else if let Value::Object(object) = root {
+        if object.values().any(|v| v == "red") {
+            Box::new(std::iter::empty())
+        } else {
+            Box::new(object.values().flat_map(extract_non_red_numbers))
+        }
+    } else if let Value::Number(number) = root {
+        Box::new(std::iter::once(
+            number
+                .as_i64()
+                .ok_or_else(|| Error::new(format!("not an integer: {number}"))),
+        ))
+    } else {
+        Box::new(std::iter::empty())
+    }
+}
+
+/// The sum of all numbers in the JSON document of the puzzle input that are not in a red object.
+///
+/// # Errors
+///
+/// Returns an `Err` if the input is not ASCII, not valid JSON or contains a number that is not
+/// an integer, or if the sum is out of the range of `i64`.
+pub fn part2(input: &str) -> Result<i64> {
+    Day12::part2(&Day12::parse(input)?)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 12;
+    const INPUT_SIZE: usize = 2000;
+
+    type Input = String;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<String> {
+        day_12_1::parse(input)
+    }
+
+    fn part1(input: &String) -> Result<i64> {
+        day_12_1::sum_numbers(input)
+    }
+
+    fn part2(input: &String) -> Result<i64> {
+        let root: Value =
+            serde_json::from_str(input).map_err(|e| Error::new(format!("invalid JSON: {e}")))?;
+        day_12_1::checked_sum(extract_non_red_numbers(&root))
+    }
+
+    /// Generates a JSON array of nested objects, arrays, numbers and strings with about `size`
+    /// numbers and strings in total.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        fn value(rng: &mut Rng, leaves: &mut usize, depth: usize) -> String {
+            const COLORS: [&str; 6] = ["red", "orange", "yellow", "green", "blue", "violet"];
+
+            if *leaves > 1 && depth < 6 && rng.ratio(1, 3) {
+                let children: Vec<_> = (0..rng.range(1..=6))
+                    .map(|_| value(rng, leaves, depth + 1))
+                    .collect();
+                if rng.ratio(1, 2) {
+                    let members: Vec<_> = children
+                        .iter()
+                        .zip(b'a'..)
+                        .map(|(child, key)| format!("\"{}\":{child}", char::from(key)))
+                        .collect();
+                    format!("{{{}}}", members.join(","))
+                } else {
+                    format!("[{}]", children.join(","))
+                }
+            } else {
+                *leaves = leaves.saturating_sub(1);
+                if rng.ratio(1, 2) {
+                    rng.range(-50..=200).to_string()
+                } else {
+                    format!("\"{}\"", rng.choose(&COLORS))
+                }
+            }
+        }
+
+        let mut leaves = size;
+        let mut values = Vec::new();
+        while leaves > 0 {
+            values.push(value(rng, &mut leaves, 1));
+        }
+        Some(format!("[{}]\n", values.join(",")))
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_array() {
+        let numbers: Vec<_> = extract_non_red_numbers(&serde_json::from_str("[1,2,3]").unwrap())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(numbers, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_object_with_red() {
+        let numbers: Vec<_> =
+            extract_non_red_numbers(&serde_json::from_str(r#"[1,{"c":"red","b":2},3] "#).unwrap())
+                .collect::<Result<_>>()
+                .unwrap();
+        assert_eq!(numbers, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_object_with_top_level_red() {
+        let numbers: Vec<_> = extract_non_red_numbers(
+            &serde_json::from_str(r#"{"d":"red","e":[1,2,3,4],"f":5}"#).unwrap(),
+        )
+        .collect::<Result<_>>()
+        .unwrap();
+        assert_eq!(numbers, Vec::<i64>::new());
+    }
+
+    #[test]
+    fn test_array_with_red() {
+        let numbers: Vec<_> =
+            extract_non_red_numbers(&serde_json::from_str(r#"[1,"red",5]"#).unwrap())
+                .collect::<Result<_>>()
+                .unwrap();
+        assert_eq!(numbers, vec![1, 5]);
+    }
+
+    #[test]
+    fn test_non_integer() {
+        for document in ["[1,1.5]", "[1e5]", "[1e300]", "[9223372036854775808]"] {
+            let root = serde_json::from_str(document).unwrap();
+            assert!(extract_non_red_numbers(&root)
+                .collect::<Result<Vec<_>>>()
+                .is_err());
+            assert!(part1(document).is_err());
+        }
+        assert!(part2(r#"{"a":[2,0.5]}"#).is_err());
+        assert_eq!(part2(r#"{"a":[2,0.5],"b":"red"}"#).unwrap(), 0);
+        assert!(part2("[9223372036854775807,1]").is_err());
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(
+        super::Day12:
+        array,
+        object,
+        nested_array,
+        nested_object,
+        array_in_object,
+        object_in_array,
+        empty_array,
+        empty_object,
+        red_object,
+        red_top_level,
+        red_in_array,
+    );
+}
@@ -2,6 +2,7 @@ use itertools::Itertools;
 use std::collections::HashMap;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Name(pub String);
 
 impl From<&str> for Name {
@@ -11,12 +12,14 @@ impl From<&str> for Name {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Action {
     Gain,
     Lose,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Information {
     person: Name,
     action: Action,
@@ -27,7 +30,7 @@ pub struct Information {
 mod parsers {
     use nom::{
         branch::alt, bytes::complete::tag, character::complete::alpha1, combinator::map,
-        sequence::terminated, sequence::tuple, IResult,
+        combinator::opt, sequence::terminated, sequence::tuple, IResult,
     };
 
     use super::{Action, Information, Name};
@@ -60,7 +63,7 @@ mod parsers {
             tag(" "),
             happiness,
             tag(" by sitting next to "),
-            name,
+            terminated(name, opt(tag("."))),
         ))(input)?;
         Ok((
             input,
@@ -74,11 +77,36 @@ mod parsers {
     }
 }
 
-impl<'a> TryFrom<&'a str> for Information {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
+impl TryFrom<&str> for Information {
+    type Error = common::Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        common::error::parse_complete(input, parsers::information)
+    }
+}
+
+impl std::fmt::Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Gain => write!(f, "gain"),
+            Self::Lose => write!(f, "lose"),
+        }
+    }
+}
 
-    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        parsers::information(input).map(|(_, c)| c)
+impl std::fmt::Display for Information {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} would {} {} happiness units by sitting next to {}.",
+            self.person, self.action, self.happiness, self.neighbor
+        )
     }
 }
 
@@ -146,8 +174,30 @@ pub fn compute_optimal_seating(matrix: &[Vec<Option<i32>>]) -> Option<(Vec<usize
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
     use std::collections::HashSet;
 
+    fn information() -> impl Strategy<Value = Information> {
+        let name = || "[A-Z][a-z]{0,9}".prop_map(Name);
+        let action = prop_oneof![Just(Action::Gain), Just(Action::Lose)];
+        (name(), action, any::<u32>(), name()).prop_map(|(person, action, happiness, neighbor)| {
+            Information {
+                person,
+                action,
+                happiness,
+                neighbor,
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_display_round_trip(information in information()) {
+            let printed = information.to_string();
+            prop_assert_eq!(Information::try_from(printed.as_str()), Ok(information));
+        }
+    }
+
     #[test]
     fn test_information() {
         assert_eq!(
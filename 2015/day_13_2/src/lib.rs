@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use common::generate::Rng;
+use common::solution::{Answer, Result, Solution, Work};
+use day_13_1::{
+    build_name_map, build_neighborhood_matrix, compute_optimal_seating, Information, Name,
+};
+
+fn optimal_seating(
+    entries: &[Information],
+    name_map: &HashMap<Name, usize>,
+) -> Result<Answer<i32>> {
+    let matrix = build_neighborhood_matrix(entries, name_map);
+    let (seating, happiness) = compute_optimal_seating(&matrix).ok_or("nobody to seat")?;
+
+    let mut names = vec![""; name_map.len()];
+    for (name, &index) in name_map {
+        names[index] = &name.0;
+    }
+    let seating = seating.iter().map(|&index| names[index]);
+    Ok(Answer::new(happiness).with_work(Work::steps("seating", seating)))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 13;
+    const INPUT_SIZE: usize = 8;
+
+    type Input = Vec<Information>;
+    type Answer1 = Answer<i32>;
+    type Answer2 = Answer<i32>;
+
+    fn parse(input: &str) -> Result<Vec<Information>> {
+        common::parse_lines(input)
+    }
+
+    fn part1(input: &Vec<Information>) -> Result<Answer<i32>> {
+        optimal_seating(input, &build_name_map(input))
+    }
+
+    fn part2(input: &Vec<Information>) -> Result<Answer<i32>> {
+        let mut name_map = build_name_map(input);
+
+        // add myself
+        name_map.insert("me".into(), name_map.len());
+
+        // Note: The new row and column of the matrix will be filled with None, which the
+        //       optimization algorithm treats as 0.
+
+        optimal_seating(input, &name_map)
+    }
+
+    /// Generates how each of `size` people feels about sitting next to each other one.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let people = rng.names(size);
+        let mut input = String::new();
+        for person in &people {
+            for neighbor in people.iter().filter(|&neighbor| neighbor != person) {
+                let action = if rng.ratio(1, 2) { "gain" } else { "lose" };
+                let happiness = rng.range(0..=100);
+                input.push_str(&format!(
+                    "{person} would {action} {happiness} happiness units by sitting next to {neighbor}.\n"
+                ));
+            }
+        }
+        Some(input)
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(super::Day13: four_guests);
+}
@@ -1,17 +1,10 @@
 //! Advent of code 2015 day 13 part 2
 
 use day_13_1::{build_name_map, build_neighborhood_matrix, compute_optimal_seating, Information};
-use std::io::BufRead;
+fn main() -> common::Result<()> {
+    let input = common::input::load(env!("CARGO_MANIFEST_DIR"), 2015, 13)?;
 
-fn main() {
-    let file = std::fs::File::open("../day_13_1/input/input.txt").unwrap();
-    let reader = std::io::BufReader::new(file);
-
-    let entries: Vec<Information> = reader
-        .lines()
-        .map(Result::unwrap)
-        .map(|s| Information::try_from(&*s).unwrap())
-        .collect();
+    let entries: Vec<Information> = common::parse_lines(&input)?;
 
     let mut name_map = build_name_map(&entries);
 
@@ -22,6 +15,7 @@ fn main() {
     //       The optimization algorithm will treat None as 0 which is exactly what we want.
     let matrix = build_neighborhood_matrix(&entries, &name_map);
 
-    let (_, happiness) = compute_optimal_seating(&matrix).unwrap();
+    let (_, happiness) = compute_optimal_seating(&matrix).ok_or("nobody to seat")?;
     println!("Optimal happiness: {happiness}");
+    Ok(())
 }
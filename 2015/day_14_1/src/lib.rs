@@ -1,4 +1,5 @@
 #[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Reindeer {
     pub name: String,
     pub speed: u32,
@@ -34,7 +35,7 @@ mod parsers {
             tag(" for "),
             time,
             tag(", but then must rest for "),
-            time,
+            terminated(time, tag(".")),
         ))(input)?;
 
         Ok((
@@ -49,11 +50,21 @@ mod parsers {
     }
 }
 
-impl<'a> TryFrom<&'a str> for Reindeer {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
+impl TryFrom<&str> for Reindeer {
+    type Error = common::Error;
 
-    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        parsers::reindeer(input).map(|(_, c)| c)
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        common::error::parse_complete(input, parsers::reindeer)
+    }
+}
+
+impl std::fmt::Display for Reindeer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} can fly {} km/s for {} seconds, but then must rest for {} seconds.",
+            self.name, self.speed, self.fly_time, self.rest_time
+        )
     }
 }
 
@@ -76,6 +87,21 @@ impl SimulateUntil for Reindeer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_display_round_trip(
+            name in "[A-Z][a-z]{0,9}",
+            speed in any::<u32>(),
+            fly_time in any::<u32>(),
+            rest_time in any::<u32>(),
+        ) {
+            let reindeer = Reindeer { name, speed, fly_time, rest_time };
+            let printed = reindeer.to_string();
+            prop_assert_eq!(Reindeer::try_from(printed.as_str()), Ok(reindeer));
+        }
+    }
 
     #[test]
     fn test_parse_reindeer() {
@@ -1,20 +1,17 @@
 //! Advent of code 2015 day 14 part 1
 
 use day_14_1::{Reindeer, SimulateUntil};
-use std::io::BufRead;
-
-fn main() {
-    let file = std::fs::File::open("input/input.txt").unwrap();
-    let reader = std::io::BufReader::new(file);
+fn main() -> common::Result<()> {
+    let input = common::input::load(env!("CARGO_MANIFEST_DIR"), 2015, 14)?;
 
     let time = 2503;
-    let distance = reader
-        .lines()
-        .map(Result::unwrap)
-        .map(|s| Reindeer::try_from(&*s).unwrap())
+    let reindeers: Vec<Reindeer> = common::parse_lines(&input)?;
+    let distance = reindeers
+        .iter()
         .map(|reindeer| reindeer.simulate(time))
         .max()
-        .unwrap();
+        .ok_or("no reindeer in the race")?;
 
     println!("Winning distance: {distance}");
+    Ok(())
 }
@@ -0,0 +1,100 @@
+use common::generate::Rng;
+use common::solution::{Result, Solution};
+use day_14_1::{Reindeer, SimulateUntil};
+use itertools::Itertools;
+
+const RACE_DURATION: u32 = 2503;
+
+fn simulate_many(time: u32, reindeers: &[Reindeer]) -> Vec<u32> {
+    let mut points = vec![0; reindeers.len()];
+    (1..=time)
+        .flat_map(|t| {
+            reindeers
+                .iter()
+                .map(|reindeer| reindeer.simulate(t))
+                .enumerate()
+                .max_set_by_key(|(_, distance)| *distance)
+        })
+        .for_each(|(i, _)| {
+            points[i] += 1;
+        });
+    points
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 14;
+    const INPUT_SIZE: usize = 9;
+
+    type Input = Vec<Reindeer>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Reindeer>> {
+        common::parse_lines(input)
+    }
+
+    fn part1(input: &Vec<Reindeer>) -> Result<u32> {
+        input
+            .iter()
+            .map(|reindeer| reindeer.simulate(RACE_DURATION))
+            .max()
+            .ok_or_else(|| "no reindeer in the race".into())
+    }
+
+    fn part2(input: &Vec<Reindeer>) -> Result<u32> {
+        simulate_many(RACE_DURATION, input)
+            .into_iter()
+            .max()
+            .ok_or_else(|| "no reindeer in the race".into())
+    }
+
+    /// Generates `size` reindeer.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(
+            rng.names(size)
+                .into_iter()
+                .map(|name| {
+                    let (speed, fly_time) = (rng.range(1..=30), rng.range(1..=20));
+                    let rest_time = rng.range(20..=200);
+                    format!(
+                        "{name} can fly {speed} km/s for {fly_time} seconds, \
+                         but then must rest for {rest_time} seconds.\n"
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simulate_many() {
+        let reindeers = vec![
+            Reindeer {
+                name: "Comet".to_string(),
+                speed: 14,
+                fly_time: 10,
+                rest_time: 127,
+            },
+            Reindeer {
+                name: "Dancer".to_string(),
+                speed: 16,
+                fly_time: 11,
+                rest_time: 162,
+            },
+        ];
+
+        assert_eq!(simulate_many(1000, &reindeers), vec![312, 689]);
+    }
+}
@@ -1,4 +1,5 @@
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IngredientEffect {
     pub capacity: i32,
     pub durability: i32,
@@ -36,6 +37,7 @@ impl std::ops::Mul<i32> for IngredientEffect {
 }
 
 #[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ingredient {
     pub name: String,
     pub effect: IngredientEffect,
@@ -108,17 +110,117 @@ mod parsers {
     }
 }
 
-impl<'a> TryFrom<&'a str> for Ingredient {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
+impl TryFrom<&str> for Ingredient {
+    type Error = common::Error;
 
-    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        parsers::ingredient(input).map(|(_, c)| c)
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        common::error::parse_complete(input, parsers::ingredient)
     }
 }
 
+impl std::fmt::Display for Ingredient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let effect = &self.effect;
+        write!(
+            f,
+            "{}: capacity {}, durability {}, flavor {}, texture {}, calories {}",
+            self.name,
+            effect.capacity,
+            effect.durability,
+            effect.flavor,
+            effect.texture,
+            effect.calories
+        )
+    }
+}
+
+trait Score {
+    fn score(&self) -> i64;
+}
+
+impl Score for IngredientEffect {
+    fn score(&self) -> i64 {
+        i64::from(self.capacity.max(0))
+            * i64::from(self.durability.max(0))
+            * i64::from(self.flavor.max(0))
+            * i64::from(self.texture.max(0))
+    }
+}
+
+fn find_remaining_optimal_composition(
+    current_effects: IngredientEffect,
+    remaining_ingredients: &[IngredientEffect],
+    remaining_amount: i32,
+    optimal_amounts: &mut [i32],
+) -> i64 {
+    assert!(!remaining_ingredients.is_empty());
+    assert!(optimal_amounts.len() == remaining_ingredients.len());
+
+    let ingredient = remaining_ingredients[0];
+    if remaining_ingredients.len() == 1 {
+        let effects = current_effects + ingredient * remaining_amount;
+        optimal_amounts[0] = remaining_amount;
+        effects.score()
+    } else {
+        let mut max_score = 0;
+        let mut best_amount = 0;
+        let mut best_inner_amount = 0;
+        for amount in 0..=remaining_amount {
+            let effects = current_effects + ingredient * amount;
+            let score = find_remaining_optimal_composition(
+                effects,
+                &remaining_ingredients[1..],
+                remaining_amount - amount,
+                &mut optimal_amounts[1..],
+            );
+            if score > max_score {
+                max_score = score;
+                best_amount = amount;
+                best_inner_amount = optimal_amounts[1];
+            }
+        }
+        optimal_amounts[0] = best_amount;
+        optimal_amounts[1] = best_inner_amount;
+        max_score
+    }
+}
+
+pub struct Composition {
+    pub amounts: Vec<i32>,
+    pub score: i64,
+}
+
+#[must_use]
+pub fn find_optimal_composition(ingredients: &[Ingredient]) -> Composition {
+    let mut amounts = vec![0; ingredients.len()];
+    let score = find_remaining_optimal_composition(
+        IngredientEffect::default(),
+        &ingredients.iter().map(|i| i.effect).collect::<Vec<_>>(),
+        100,
+        &mut amounts,
+    );
+    Composition { amounts, score }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_display_round_trip(
+            name in "[A-Z][a-z]{0,11}",
+            (capacity, durability, flavor, texture, calories) in any::<(i32, i32, i32, i32, i32)>(),
+        ) {
+            let ingredient = Ingredient {
+                name,
+                effect: IngredientEffect { capacity, durability, flavor, texture, calories },
+            };
+            let printed = ingredient.to_string();
+            prop_assert_eq!(Ingredient::try_from(printed.as_str()), Ok(ingredient));
+        }
+    }
 
     #[test]
     fn test_ingredient() {
@@ -181,4 +283,55 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_score() {
+        let butterscotch = IngredientEffect {
+            capacity: -1,
+            durability: -2,
+            flavor: 6,
+            texture: 3,
+            calories: 8,
+        };
+
+        let cinnamon = IngredientEffect {
+            capacity: 2,
+            durability: 3,
+            flavor: -2,
+            texture: -1,
+            calories: 3,
+        };
+
+        let cookie = butterscotch * 44 + cinnamon * 56;
+        assert_eq!(cookie.score(), 62842880);
+    }
+
+    #[test]
+    fn test_find_optimal_composition() {
+        let ingredients = vec![
+            Ingredient {
+                name: "Butterscotch".to_string(),
+                effect: IngredientEffect {
+                    capacity: -1,
+                    durability: -2,
+                    flavor: 6,
+                    texture: 3,
+                    calories: 8,
+                },
+            },
+            Ingredient {
+                name: "Cinnamon".to_string(),
+                effect: IngredientEffect {
+                    capacity: 2,
+                    durability: 3,
+                    flavor: -2,
+                    texture: -1,
+                    calories: 3,
+                },
+            },
+        ];
+        let composition = find_optimal_composition(&ingredients);
+        assert_eq!(composition.score, 62842880);
+        assert_eq!(composition.amounts, vec![44, 56]);
+    }
 }
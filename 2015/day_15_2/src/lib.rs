@@ -0,0 +1,195 @@
+use common::generate::Rng;
+use common::solution::{Result, Solution};
+use day_15_1::{Ingredient, IngredientEffect};
+
+trait Score {
+    fn score(&self) -> Option<i64>;
+}
+
+impl Score for IngredientEffect {
+    fn score(&self) -> Option<i64> {
+        if self.calories == 500 {
+            Some(
+                i64::from(self.capacity.max(0))
+                    * i64::from(self.durability.max(0))
+                    * i64::from(self.flavor.max(0))
+                    * i64::from(self.texture.max(0)),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+fn find_remaining_optimal_composition(
+    current_effects: IngredientEffect,
+    remaining_ingredients: &[IngredientEffect],
+    remaining_amount: i32,
+    optimal_amounts: &mut [i32],
+) -> Option<i64> {
+    assert!(!remaining_ingredients.is_empty());
+    assert!(optimal_amounts.len() == remaining_ingredients.len());
+
+    let ingredient = remaining_ingredients[0];
+    if remaining_ingredients.len() == 1 {
+        let effects = current_effects + ingredient * remaining_amount;
+        optimal_amounts[0] = remaining_amount;
+        effects.score()
+    } else {
+        let mut max_score = None;
+        let mut best_amount = 0;
+        let mut best_inner_amount = 0;
+        for amount in 0..=remaining_amount {
+            let effects = current_effects + ingredient * amount;
+            let score = find_remaining_optimal_composition(
+                effects,
+                &remaining_ingredients[1..],
+                remaining_amount - amount,
+                &mut optimal_amounts[1..],
+            );
+            if score > max_score {
+                max_score = score;
+                best_amount = amount;
+                best_inner_amount = optimal_amounts[1];
+            }
+        }
+        optimal_amounts[0] = best_amount;
+        optimal_amounts[1] = best_inner_amount;
+        max_score
+    }
+}
+
+pub struct Composition {
+    pub amounts: Vec<i32>,
+    pub score: i64,
+}
+
+#[must_use]
+pub fn find_optimal_composition(ingredients: &[Ingredient]) -> Option<Composition> {
+    let mut amounts = vec![0; ingredients.len()];
+    let score = find_remaining_optimal_composition(
+        IngredientEffect::default(),
+        &ingredients.iter().map(|i| i.effect).collect::<Vec<_>>(),
+        100,
+        &mut amounts,
+    );
+    score.map(|score| Composition { amounts, score })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 15;
+    const INPUT_SIZE: usize = 4;
+
+    type Input = Vec<Ingredient>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Ingredient>> {
+        common::parse_lines(input)
+    }
+
+    fn part1(input: &Vec<Ingredient>) -> Result<i64> {
+        if input.is_empty() {
+            return Err("no ingredients".into());
+        }
+        Ok(day_15_1::find_optimal_composition(input).score)
+    }
+
+    fn part2(input: &Vec<Ingredient>) -> Result<i64> {
+        if input.is_empty() {
+            return Err("no ingredients".into());
+        }
+        find_optimal_composition(input)
+            .map(|composition| composition.score)
+            .ok_or_else(|| "no cookie with 500 calories".into())
+    }
+
+    /// Generates `size` ingredients, the first of which has 5 calories so that there always is a
+    /// cookie with 500 calories.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(
+            rng.names(size)
+                .into_iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    let [capacity, durability, flavor, texture] =
+                        [(); 4].map(|()| rng.range(-5..=5));
+                    let calories = if i == 0 { 5 } else { rng.range(1..=9) };
+                    format!(
+                        "{name}: capacity {capacity}, durability {durability}, flavor {flavor}, \
+                         texture {texture}, calories {calories}\n"
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score() {
+        let butterscotch = IngredientEffect {
+            capacity: -1,
+            durability: -2,
+            flavor: 6,
+            texture: 3,
+            calories: 8,
+        };
+
+        let cinnamon = IngredientEffect {
+            capacity: 2,
+            durability: 3,
+            flavor: -2,
+            texture: -1,
+            calories: 3,
+        };
+
+        let cookie = butterscotch * 40 + cinnamon * 60;
+        assert_eq!(cookie.score(), Some(57600000));
+    }
+
+    #[test]
+    fn test_find_optimal_composition() {
+        let ingredients = vec![
+            Ingredient {
+                name: "Butterscotch".to_string(),
+                effect: IngredientEffect {
+                    capacity: -1,
+                    durability: -2,
+                    flavor: 6,
+                    texture: 3,
+                    calories: 8,
+                },
+            },
+            Ingredient {
+                name: "Cinnamon".to_string(),
+                effect: IngredientEffect {
+                    capacity: 2,
+                    durability: 3,
+                    flavor: -2,
+                    texture: -1,
+                    calories: 3,
+                },
+            },
+        ];
+        let composition = find_optimal_composition(&ingredients).unwrap();
+        assert_eq!(composition.score, 57600000);
+        assert_eq!(composition.amounts, vec![40, 60]);
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(super::Day15: two_ingredients);
+}
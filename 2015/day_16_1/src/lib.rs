@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use common::Result;
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MfcsamRecord {
+    pub name: String,
+    pub properties: HashMap<String, i32>,
+}
+
+impl TryFrom<&str> for MfcsamRecord {
+    type Error = common::Error;
+
+    /// Parses a record like `Sue 1: cars: 9, akitas: 3, goldfish: 0`.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let (name, properties) = input
+            .split_once(": ")
+            .ok_or("expected '<name>: <properties>'")?;
+        let properties = properties
+            .split(", ")
+            .map(|property| {
+                let (key, value) = property
+                    .split_once(": ")
+                    .ok_or_else(|| format!("expected '<key>: <value>', got '{property}'"))?;
+                Ok((key.to_string(), value.parse()?))
+            })
+            .collect::<Result<_, common::Error>>()?;
+        Ok(Self {
+            name: name.to_string(),
+            properties,
+        })
+    }
+}
+
+impl MfcsamRecord {
+    #[must_use]
+    pub fn matches(&self, other: &Self) -> bool {
+        self.properties
+            .iter()
+            .all(|(key, value)| other.properties.get(key).is_none_or(|v| v == value))
+    }
+}
+
+/// The record printed by the MFCSAM on the ticker tape.
+#[must_use]
+pub fn ticker_tape() -> MfcsamRecord {
+    MfcsamRecord {
+        name: String::new(),
+        properties: [
+            ("children".to_string(), 3),
+            ("cats".to_string(), 7),
+            ("samoyeds".to_string(), 2),
+            ("pomeranians".to_string(), 3),
+            ("akitas".to_string(), 0),
+            ("vizlsas".to_string(), 0),
+            ("goldfish".to_string(), 5),
+            ("trees".to_string(), 3),
+            ("cars".to_string(), 2),
+            ("perfumes".to_string(), 1),
+        ]
+        .into_iter()
+        .collect(),
+    }
+}
+
+/// Extracts the number of the Aunt Sue from a record name like `Sue 42`.
+///
+/// # Errors
+///
+/// Returns an `Err` if the name is not of that form.
+pub fn sue_number(record: &MfcsamRecord) -> Result<u32> {
+    record
+        .name
+        .strip_prefix("Sue ")
+        .and_then(|number| number.parse().ok())
+        .ok_or_else(|| format!("not an Aunt Sue: {}", record.name).into())
+}
+
+/// The number of the Aunt Sue of the puzzle input that matches the ticker tape.
+///
+/// # Errors
+///
+/// Returns an `Err` if a record is malformed or no Aunt Sue matches.
+pub fn part1(input: &str) -> Result<u32> {
+    let sample = ticker_tape();
+    let sues: Vec<MfcsamRecord> = common::parse_lines(input)?;
+    let sue = sues
+        .iter()
+        .find(|sue| sample.matches(sue))
+        .ok_or("no matching Aunt Sue")?;
+    sue_number(sue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        let record = MfcsamRecord::try_from("Sue 1: cars: 9, akitas: 3, goldfish: 0").unwrap();
+        assert_eq!(record.name, "Sue 1");
+        assert_eq!(record.properties.len(), 3);
+        assert_eq!(record.properties["cars"], 9);
+        assert_eq!(record.properties["akitas"], 3);
+        assert_eq!(record.properties["goldfish"], 0);
+    }
+
+    #[test]
+    fn test_try_from_invalid() {
+        assert!(MfcsamRecord::try_from("").is_err());
+        assert!(MfcsamRecord::try_from("Sue 1").is_err());
+        assert!(MfcsamRecord::try_from("Sue 1: cars").is_err());
+        assert!(MfcsamRecord::try_from("Sue 1: cars: 9, akitas").is_err());
+        assert!(MfcsamRecord::try_from("Sue 1: cars: many").is_err());
+    }
+
+    #[test]
+    fn test_matches() {
+        let sample = ticker_tape();
+        assert!(sample.matches(&MfcsamRecord::try_from("Sue 1: cars: 2, akitas: 0").unwrap()));
+        assert!(!sample.matches(&MfcsamRecord::try_from("Sue 2: cars: 2, akitas: 1").unwrap()));
+    }
+
+    #[test]
+    fn test_sue_number() {
+        assert_eq!(
+            sue_number(&MfcsamRecord::try_from("Sue 42: cats: 1").unwrap()).unwrap(),
+            42
+        );
+        assert!(sue_number(&MfcsamRecord::try_from("Bob: cats: 1").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("Sue 1: cars: 3\nSue 2: cars: 2, cats: 7\n"), Ok(2));
+        assert!(part1("Sue 1: cars: 3\n").is_err());
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use common::generate::Rng;
+use common::solution::{Result, Solution};
+pub use day_16_1::part1;
+use day_16_1::{sue_number, MfcsamRecord};
+
+/// How the reading of the outdated MFCSAM relates to the real amount of a compound.
+pub enum Comparison {
+    Equal,
+    Less,
+    Greater,
+}
+
+/// A sample analyzed by the outdated MFCSAM, which only gives ranges for some compounds.
+pub struct McfsamSample {
+    properties: HashMap<String, (i32, Comparison)>,
+}
+
+impl McfsamSample {
+    #[must_use]
+    pub fn matches(&self, record: &MfcsamRecord) -> bool {
+        record.properties.iter().all(|(key, value)| {
+            self.properties.get(key).is_none_or(|(v, c)| match c {
+                Comparison::Equal => value == v,
+                Comparison::Less => value < v,
+                Comparison::Greater => value > v,
+            })
+        })
+    }
+}
+
+/// The record printed by the MFCSAM, this time with the outdated retroencabulator in mind.
+#[must_use]
+pub fn ticker_tape() -> McfsamSample {
+    McfsamSample {
+        properties: [
+            ("children".to_string(), (3, Comparison::Equal)),
+            ("cats".to_string(), (7, Comparison::Greater)),
+            ("samoyeds".to_string(), (2, Comparison::Equal)),
+            ("pomeranians".to_string(), (3, Comparison::Less)),
+            ("akitas".to_string(), (0, Comparison::Equal)),
+            ("vizlsas".to_string(), (0, Comparison::Equal)),
+            ("goldfish".to_string(), (5, Comparison::Less)),
+            ("trees".to_string(), (3, Comparison::Greater)),
+            ("cars".to_string(), (2, Comparison::Equal)),
+            ("perfumes".to_string(), (1, Comparison::Equal)),
+        ]
+        .into_iter()
+        .collect(),
+    }
+}
+
+/// The number of the Aunt Sue of the puzzle input that matches the ranges of the outdated
+/// ticker tape.
+///
+/// # Errors
+///
+/// Returns an `Err` if a record is malformed or no Aunt Sue matches.
+pub fn part2(input: &str) -> Result<u32> {
+    Day16::part2(&Day16::parse(input)?)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 16;
+    const INPUT_SIZE: usize = 500;
+
+    type Input = Vec<MfcsamRecord>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<MfcsamRecord>> {
+        common::parse_lines(input)
+    }
+
+    fn part1(input: &Vec<MfcsamRecord>) -> Result<u32> {
+        let sample = day_16_1::ticker_tape();
+        let sue = input
+            .iter()
+            .find(|sue| sample.matches(sue))
+            .ok_or("no matching Aunt Sue")?;
+        sue_number(sue)
+    }
+
+    fn part2(input: &Vec<MfcsamRecord>) -> Result<u32> {
+        let sample = ticker_tape();
+        let sue = input
+            .iter()
+            .find(|sue| sample.matches(sue))
+            .ok_or("no matching Aunt Sue")?;
+        sue_number(sue)
+    }
+
+    /// Generates `size` Aunt Sues, one matching the ticker tape of each part.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const COMPOUNDS: [&str; 10] = [
+            "children",
+            "cats",
+            "samoyeds",
+            "pomeranians",
+            "akitas",
+            "vizslas",
+            "goldfish",
+            "trees",
+            "cars",
+            "perfumes",
+        ];
+        // Compounds that are compared exactly in both parts, so getting one of them wrong rules a
+        // Sue out in both.
+        const EXACT: [&str; 5] = ["children", "samoyeds", "akitas", "cars", "perfumes"];
+        // Compounds that are compared differently in each part, so the Sue matching one part
+        // cannot match the other.
+        const RANGED: [&str; 4] = ["cats", "pomeranians", "goldfish", "trees"];
+
+        let exact = day_16_1::ticker_tape();
+        let outdated = ticker_tape();
+        let size = size.max(2);
+        let first = rng.index(size);
+        let second = (first + rng.range(1..=size - 1)) % size;
+
+        let mut input = String::new();
+        for sue in 0..size {
+            let mut compounds = COMPOUNDS;
+            rng.shuffle(&mut compounds);
+            let mut compounds = compounds[..3].to_vec();
+            let required = if sue == first || sue == second {
+                &RANGED[..]
+            } else {
+                &EXACT[..]
+            };
+            if !compounds.iter().any(|compound| required.contains(compound)) {
+                compounds[0] = *rng.choose(required);
+            }
+
+            let properties: Vec<_> = compounds
+                .into_iter()
+                .map(|compound| {
+                    let value = if sue == first {
+                        exact.properties.get(compound).copied()
+                    } else if sue == second {
+                        outdated.properties.get(compound).map(
+                            |(value, comparison)| match comparison {
+                                Comparison::Equal => *value,
+                                Comparison::Less => rng.range(0..=value - 1),
+                                Comparison::Greater => rng.range(value + 1..=value + 5),
+                            },
+                        )
+                    } else if EXACT.contains(&compound) {
+                        let expected = exact.properties[compound];
+                        Some((expected + rng.range(1..=10)) % 11)
+                    } else {
+                        None
+                    };
+                    let value = value.unwrap_or_else(|| rng.range(0..=10));
+                    format!("{compound}: {value}")
+                })
+                .collect();
+            input.push_str(&format!("Sue {}: {}\n", sue + 1, properties.join(", ")));
+        }
+        Some(input)
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        let sample = ticker_tape();
+        assert!(sample.matches(&MfcsamRecord::try_from("Sue 1: cats: 8, goldfish: 4").unwrap()));
+        assert!(!sample.matches(&MfcsamRecord::try_from("Sue 2: cats: 7, goldfish: 4").unwrap()));
+        assert!(!sample.matches(&MfcsamRecord::try_from("Sue 3: cats: 8, goldfish: 5").unwrap()));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day16::generate(&mut Rng::new(seed), 20).unwrap();
+            let sues = Day16::parse(&input).unwrap();
+            let exact = day_16_1::ticker_tape();
+            assert_eq!(sues.iter().filter(|sue| exact.matches(sue)).count(), 1);
+            assert_eq!(
+                sues.iter().filter(|sue| ticker_tape().matches(sue)).count(),
+                1
+            );
+            assert_ne!(Day16::part1(&sues).unwrap(), Day16::part2(&sues).unwrap());
+        }
+    }
+}
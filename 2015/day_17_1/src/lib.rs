@@ -0,0 +1,41 @@
+use common::{BitSet, LineParser, Result};
+
+/// The liters of eggnog that have to fit into the containers.
+pub const EGGNOG_LITERS: u32 = 150;
+
+#[must_use]
+pub fn count_combinations(containers: &[u32], target: u32) -> usize {
+    // skip the empty set of containers
+    BitSet::full(containers.len())
+        .subsets()
+        .skip(1)
+        .filter(|subset| subset.iter().map(|j| containers[j]).sum::<u32>() == target)
+        .count()
+}
+
+/// The number of combinations of the containers of the puzzle input, one capacity per line, that
+/// hold the eggnog exactly.
+///
+/// # Errors
+///
+/// Returns an `Err` if a capacity is not a number.
+pub fn part1(input: &str) -> Result<usize> {
+    let containers: Vec<u32> = LineParser::new().parse_with(input, str::parse)?;
+    Ok(count_combinations(&containers, EGGNOG_LITERS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combinations() {
+        assert_eq!(count_combinations(&[20, 15, 10, 5, 5], 25), 4);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("100\n50\n50\n"), Ok(2));
+        assert!(part1("100\nmany\n").is_err());
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use common::generate::Rng;
+use common::solution::{Result, Solution};
+use common::{BitSet, LineParser};
+pub use day_17_1::part1;
+use day_17_1::EGGNOG_LITERS;
+
+/// Counts the combinations of containers that hold exactly `target`, by the number of containers
+/// used. Numbers of containers without any combination are left out.
+#[must_use]
+pub fn count_minimal_combinations(containers: &[u32], target: u32) -> HashMap<usize, usize> {
+    let all = BitSet::full(containers.len());
+    let mut counts = HashMap::new();
+    for k in 1..=containers.len() {
+        let count = all
+            .k_subsets(k)
+            .filter(|subset| subset.iter().map(|j| containers[j]).sum::<u32>() == target)
+            .count();
+        if count > 0 {
+            counts.insert(k, count);
+        }
+    }
+    counts
+}
+
+/// The number of combinations of the fewest containers of the puzzle input that hold the eggnog
+/// exactly.
+///
+/// # Errors
+///
+/// Returns an `Err` if a capacity is not a number or no combination fits the eggnog.
+pub fn part2(input: &str) -> Result<usize> {
+    Day17::part2(&Day17::parse(input)?)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 17;
+    const INPUT_SIZE: usize = 20;
+
+    type Input = Vec<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<u32>> {
+        LineParser::new().parse_with(input, str::parse)
+    }
+
+    fn part1(input: &Vec<u32>) -> Result<usize> {
+        Ok(day_17_1::count_combinations(input, EGGNOG_LITERS))
+    }
+
+    fn part2(input: &Vec<u32>) -> Result<usize> {
+        count_minimal_combinations(input, EGGNOG_LITERS)
+            .into_iter()
+            .min()
+            .map(|(_, combinations)| combinations)
+            .ok_or_else(|| "no combination of containers fits the eggnog".into())
+    }
+
+    /// Generates `size` containers, some of which hold the eggnog exactly.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut containers = Vec::new();
+        let mut left = EGGNOG_LITERS;
+        while left > 50 {
+            let container = rng.range(20..=50);
+            containers.push(container);
+            left -= container;
+        }
+        containers.push(left);
+        while containers.len() < size {
+            containers.push(rng.range(5..=50));
+        }
+        rng.shuffle(&mut containers);
+        Some(containers.iter().map(|c| format!("{c}\n")).collect())
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combinations() {
+        let mut counts: Vec<_> = count_minimal_combinations(&[20, 15, 10, 5, 5], 25)
+            .into_iter()
+            .collect();
+        counts.sort();
+        assert_eq!(counts, vec![(2, 3), (3, 1)]);
+    }
+}
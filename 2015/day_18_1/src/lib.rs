@@ -1,6 +1,9 @@
-#[derive(Debug, PartialEq, Eq)]
+use common::Grid;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LightGrid {
-    lights: Vec<Vec<bool>>,
+    lights: Grid<bool>,
 }
 
 impl LightGrid {
@@ -16,60 +19,34 @@ impl LightGrid {
     ///
     /// # Errors
     ///
-    /// Returns an `Err` if the input contains invalid characters (not '#' or '.').
-    pub fn from_lines(lines: impl Iterator<Item = impl AsRef<str>>) -> Result<Self, &'static str> {
-        let lights = lines
-            .map(|line| {
-                line.as_ref()
-                    .chars()
-                    .map(|c| match c {
-                        '#' => Some(true),
-                        '.' => Some(false),
-                        _ => None,
-                    })
-                    .collect::<Option<Vec<bool>>>()
-            })
-            .collect::<Option<Vec<Vec<bool>>>>();
-        lights.map(|lights| Self { lights }).ok_or("Invalid input")
+    /// Returns an `Err` pointing at the first invalid character (not '#' or '.') or at a line of
+    /// different length.
+    pub fn from_lines(lines: impl Iterator<Item = impl AsRef<str>>) -> Result<Self, common::Error> {
+        let lights = Grid::from_lines(lines, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(format!("invalid light '{c}'")),
+        })?;
+        Ok(Self { lights })
     }
 
     #[must_use]
     pub fn get(&self, x: usize, y: usize) -> Option<bool> {
-        self.lights.get(y).and_then(|row| row.get(x)).copied()
-    }
-
-    fn get_neighbor(&self, x: usize, y: usize, offset_x: isize, offset_y: isize) -> Option<bool> {
-        let x = isize::try_from(x).unwrap() + offset_x;
-        let y = isize::try_from(y).unwrap() + offset_y;
-        if x < 0 || y < 0 {
-            return None;
-        }
-        self.get(usize::try_from(x).unwrap(), usize::try_from(y).unwrap())
+        self.lights.get((x, y)).copied()
     }
 
     fn count_on_neighbors(&self, x: usize, y: usize) -> usize {
-        let mut count = 0;
-        for offset_x in -1..=1 {
-            for offset_y in -1..=1 {
-                if offset_x == 0 && offset_y == 0 {
-                    continue;
-                }
-                if self.get_neighbor(x, y, offset_x, offset_y) == Some(true) {
-                    count += 1;
-                }
-            }
-        }
-        count
+        self.lights
+            .neighbors8((x, y))
+            .filter(|&position| self.lights[position])
+            .count()
     }
 
     #[must_use]
     pub fn step(&self) -> Self {
-        let mut lights = self.lights.clone();
-        lights.iter_mut().enumerate().for_each(|(y, row)| {
-            row.iter_mut().enumerate().for_each(|(x, light)| {
-                let count = self.count_on_neighbors(x, y);
-                *light = matches!((*light, count), (true, 2..=3) | (false, 3));
-            });
+        let lights = self.lights.map(|(x, y), &light| {
+            let count = self.count_on_neighbors(x, y);
+            matches!((light, count), (true, 2..=3) | (false, 3))
         });
 
         Self { lights }
@@ -77,19 +54,16 @@ impl LightGrid {
 
     #[must_use]
     pub fn count_on(&self) -> usize {
-        self.lights
-            .iter()
-            .map(|row| row.iter().filter(|&&light| light).count())
-            .sum()
+        self.lights.values().filter(|&&light| light).count()
     }
 
     #[must_use]
     pub fn size(&self) -> usize {
-        self.lights.len()
+        self.lights.height()
     }
 
     pub fn set(&mut self, x: usize, y: usize, value: bool) {
-        if let Some(light) = self.lights.get_mut(y).and_then(|row| row.get_mut(x)) {
+        if let Some(light) = self.lights.get_mut((x, y)) {
             *light = value;
         }
     }
@@ -98,6 +72,7 @@ impl LightGrid {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::geometry::Dir8;
 
     const INPUT: &str = r#"
         .#.#.#
@@ -119,18 +94,26 @@ mod tests {
         assert_eq!(grid.get(0, 1), Some(false));
     }
 
+    #[test]
+    fn test_from_lines_invalid() {
+        let error = LightGrid::from_lines(["#..", "#x#"].into_iter()).unwrap_err();
+        assert_eq!(error.to_string(), "2:2: invalid light 'x'\n    #x#\n     ^");
+    }
+
     #[test]
     fn test_get_neighbor() {
         let grid =
             LightGrid::from_lines(INPUT.lines().map(str::trim).filter(|line| !line.is_empty()))
                 .unwrap();
-        assert_eq!(grid.get_neighbor(0, 0, 0, 0), Some(false));
-        assert_eq!(grid.get_neighbor(0, 0, 1, 0), Some(true));
-        assert_eq!(grid.get_neighbor(0, 0, 0, 1), Some(false));
-        assert_eq!(grid.get_neighbor(0, 0, 1, 1), Some(false));
-        assert_eq!(grid.get_neighbor(0, 0, -1, 0), None);
-        assert_eq!(grid.get_neighbor(0, 0, 0, -1), None);
-        assert_eq!(grid.get_neighbor(0, 0, -1, -1), None);
+        let get_neighbor =
+            |x, y, direction| grid.lights.step((x, y), direction).map(|p| grid.lights[p]);
+        assert!(!grid.lights[(0, 0)]);
+        assert_eq!(get_neighbor(0, 0, Dir8::East), Some(true));
+        assert_eq!(get_neighbor(0, 0, Dir8::South), Some(false));
+        assert_eq!(get_neighbor(0, 0, Dir8::SouthEast), Some(false));
+        assert_eq!(get_neighbor(0, 0, Dir8::West), None);
+        assert_eq!(get_neighbor(0, 0, Dir8::North), None);
+        assert_eq!(get_neighbor(0, 0, Dir8::NorthWest), None);
     }
 
     #[test]
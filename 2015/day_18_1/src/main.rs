@@ -1,16 +1,14 @@
 //! Advent of code 2015 day 18 part 1
 
-use std::io::BufRead;
-
 use day_18_1::LightGrid;
 
-fn main() {
-    let file = std::fs::File::open("input/input.txt").unwrap();
-    let reader = std::io::BufReader::new(file);
-    let grid = LightGrid::from_lines(reader.lines().map(Result::unwrap)).unwrap();
+fn main() -> common::Result<()> {
+    let input = common::input::load(env!("CARGO_MANIFEST_DIR"), 2015, 18)?;
+    let grid = LightGrid::from_lines(input.lines())?;
 
     let grid = (0..100).fold(grid, |grid, _| grid.step());
     println!("Lights on: {}", grid.count_on());
+    Ok(())
 }
 
 #[cfg(test)]
@@ -0,0 +1,193 @@
+use common::generate::Rng;
+use common::solution::{Result, Solution};
+use day_18_1::LightGrid;
+
+const STEPS: usize = 100;
+
+fn set_corners_on(grid: &mut LightGrid) {
+    let size = grid.size();
+    grid.set(0, 0, true);
+    grid.set(size - 1, 0, true);
+    grid.set(0, size - 1, true);
+    grid.set(size - 1, size - 1, true);
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 18;
+    const INPUT_SIZE: usize = 100;
+
+    type Input = LightGrid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<LightGrid> {
+        LightGrid::from_lines(input.lines())
+    }
+
+    fn part1(input: &LightGrid) -> Result<usize> {
+        let grid = (0..STEPS).fold(input.clone(), |grid, _| grid.step());
+        Ok(grid.count_on())
+    }
+
+    fn part2(input: &LightGrid) -> Result<usize> {
+        let mut grid = input.clone();
+        set_corners_on(&mut grid);
+        for _ in 0..STEPS {
+            grid = grid.step();
+            set_corners_on(&mut grid);
+        }
+        Ok(grid.count_on())
+    }
+
+    /// Generates a grid of `size` by `size` lights.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some((0..size).map(|_| rng.string(size, b"#.") + "\n").collect())
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let mut grid = LightGrid::from_lines(
+            r#"
+                ##.#.#
+                ...##.
+                #....#
+                ..#...
+                #.#..#
+                ####.#
+            "#
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty()),
+        )
+        .unwrap();
+        set_corners_on(&mut grid);
+
+        let mut grid = grid.step();
+        set_corners_on(&mut grid);
+        let expected = LightGrid::from_lines(
+            r#"
+                #.##.#
+                ####.#
+                ...##.
+                ......
+                #...#.
+                #.####
+            "#
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty()),
+        )
+        .unwrap();
+        assert_eq!(grid, expected);
+
+        let mut grid = grid.step();
+        set_corners_on(&mut grid);
+        let expected = LightGrid::from_lines(
+            r#"
+                #..#.#
+                #....#
+                .#.##.
+                ...##.
+                .#..##
+                ##.###
+            "#
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty()),
+        )
+        .unwrap();
+        assert_eq!(grid, expected);
+
+        let mut grid = grid.step();
+        set_corners_on(&mut grid);
+        let expected = LightGrid::from_lines(
+            r#"
+                #...##
+                ####.#
+                ..##.#
+                ......
+                ##....
+                ####.#
+            "#
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty()),
+        )
+        .unwrap();
+        assert_eq!(grid, expected);
+
+        let mut grid = grid.step();
+        set_corners_on(&mut grid);
+        let expected = LightGrid::from_lines(
+            r#"
+                #.####
+                #....#
+                ...#..
+                .##...
+                #.....
+                #.#..#
+            "#
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty()),
+        )
+        .unwrap();
+        assert_eq!(grid, expected);
+
+        let mut grid = grid.step();
+        set_corners_on(&mut grid);
+        let expected = LightGrid::from_lines(
+            r#"
+                ##.###
+                .##..#
+                .##...
+                .##...
+                #.#...
+                ##...#
+            "#
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty()),
+        )
+        .unwrap();
+        assert_eq!(grid, expected);
+    }
+
+    #[test]
+    fn test_loop_step() {
+        let mut grid = LightGrid::from_lines(
+            r#"
+                ##.#.#
+                ...##.
+                #....#
+                ..#...
+                #.#..#
+                ####.#
+            "#
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty()),
+        )
+        .unwrap();
+
+        set_corners_on(&mut grid);
+        for _ in 0..5 {
+            grid = grid.step();
+            set_corners_on(&mut grid);
+        }
+        assert_eq!(grid.count_on(), 17);
+    }
+}
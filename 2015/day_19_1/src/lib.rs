@@ -1,15 +1,26 @@
+use std::collections::{HashMap, HashSet};
+
+use common::Result;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Replacement {
     pub original: String,
     pub replacement: String,
 }
 
-#[must_use]
-pub fn parse_replacement(line: &str) -> Replacement {
-    let parts: Vec<&str> = line.split(" => ").collect();
-    Replacement {
-        original: parts[0].to_string(),
-        replacement: parts[1].to_string(),
-    }
+/// Parses a replacement like `H => HO`.
+///
+/// # Errors
+///
+/// Returns an `Err` if the line has no ` => `.
+pub fn parse_replacement(line: &str) -> Result<Replacement> {
+    let (original, replacement) = line
+        .split_once(" => ")
+        .ok_or("expected '<original> => <replacement>'")?;
+    Ok(Replacement {
+        original: original.to_string(),
+        replacement: replacement.to_string(),
+    })
 }
 
 #[must_use]
@@ -31,20 +42,83 @@ pub fn tokenize_molecule(molecule: &str) -> Vec<String> {
     tokens
 }
 
+#[must_use]
+pub fn create_unique_modifications(
+    molecule: &[String],
+    replacements: &[Replacement],
+) -> HashSet<String> {
+    let mut replacement_map = HashMap::new();
+    for replacement in replacements {
+        replacement_map
+            .entry(&replacement.original)
+            .or_insert(Vec::new())
+            .push(&replacement.replacement);
+    }
+
+    let mut unique_modifications = HashSet::new();
+    for (i, token) in molecule.iter().enumerate() {
+        let replacements_for_token = replacement_map.entry(token).or_default();
+        for selected_replacement in replacements_for_token {
+            let molecule_string: String = molecule
+                .iter()
+                .enumerate()
+                .map(|(j, t)| {
+                    if i == j {
+                        selected_replacement.clone()
+                    } else {
+                        t.clone()
+                    }
+                })
+                .collect();
+            unique_modifications.insert(molecule_string);
+        }
+    }
+    unique_modifications
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_parse_replacement() {
-        let replacement = parse_replacement("H => HO");
+        let replacement = parse_replacement("H => HO").unwrap();
         assert_eq!(replacement.original, "H");
         assert_eq!(replacement.replacement, "HO");
     }
 
+    #[test]
+    fn test_parse_replacement_invalid() {
+        assert!(parse_replacement("").is_err());
+        assert!(parse_replacement("H =>").is_err());
+        assert!(parse_replacement("H -> HO").is_err());
+    }
+
     #[test]
     fn test_tokenize_molecule() {
         assert_eq!(tokenize_molecule("HOH"), ["H", "O", "H"]);
         assert_eq!(tokenize_molecule("AbCdEf"), ["Ab", "Cd", "Ef"]);
     }
+
+    #[test]
+    fn test_create_unique_modifications() {
+        let molecule = vec!["H".to_string(), "O".to_string(), "H".to_string()];
+        let replacements = vec![
+            parse_replacement("H => HO").unwrap(),
+            parse_replacement("H => OH").unwrap(),
+            parse_replacement("O => HH").unwrap(),
+        ];
+        let expected = [
+            "HOOH".to_string(),
+            "HOHO".to_string(),
+            "OHOH".to_string(),
+            "HHHH".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let unique_modifications = create_unique_modifications(&molecule, &replacements);
+        assert_eq!(unique_modifications, expected);
+        assert_eq!(unique_modifications.len(), 4);
+    }
 }
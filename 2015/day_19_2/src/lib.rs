@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+
+use common::generate::Rng;
+use common::search;
+use common::solution::{Result, Solution};
+use common::LineParser;
+use day_19_1::{create_unique_modifications, parse_replacement, tokenize_molecule, Replacement};
+
+fn bfs(molecule: &[String], replacements: &[Replacement]) -> Option<usize> {
+    let mut replacement_map = HashMap::new();
+    for replacement in replacements {
+        replacement_map
+            .entry(&replacement.original)
+            .or_insert(Vec::new())
+            .push(tokenize_molecule(&replacement.replacement));
+    }
+
+    // Replacements never shrink a molecule, so longer candidates can be dropped.
+    let successors = |candidate: &Vec<String>| {
+        let mut new_molecules = Vec::new();
+        for (i, token) in candidate.iter().enumerate() {
+            for replacement in replacement_map.get(token).into_iter().flatten() {
+                let mut new_molecule = candidate.clone();
+                new_molecule.splice(i..=i, replacement.iter().cloned());
+                if new_molecule.len() <= molecule.len() {
+                    new_molecules.push(new_molecule);
+                }
+            }
+        }
+        new_molecules
+    };
+    search::bfs(vec!["e".to_string()], successors, |candidate| {
+        candidate == molecule
+    })
+    .cost()
+}
+
+fn bfs_with_cheating(molecule: &[String], replacements: &[Replacement]) -> Result<usize> {
+    // This is a cheating solution that works for the input.
+    // No simple algorithm like BFS or even A* can solve the general case in reasonable time.
+
+    // The solution is based on
+    // https://github.com/rene-d/advent-of-rust/blob/main/2015/day19/day19.rs
+    // which is in turn based on
+    // https://github.com/petertseng/adventofcode-rb-2015/blob/e968bc59e527e47ca9a28b313f58cc04b6f074cb/19_molecule_replacement.rb#L54
+
+    // Probably the general case can be solved with the CYK algorithm but I don't have time to implement it.
+
+    if molecule.len() < 10 {
+        bfs(molecule, replacements).ok_or_else(|| "the molecule cannot be made from 'e'".into())
+    } else {
+        count_steps(molecule, replacements)
+    }
+}
+
+/// The number of steps read off the molecule, assuming replacements shaped like the real ones.
+///
+/// Fails if there is no replacement for `e`, if `Rn` and `Ar` are unbalanced or if the molecule
+/// is too short to be made that way.
+fn count_steps(molecule: &[String], replacements: &[Replacement]) -> Result<usize> {
+    let max_e = replacements
+        .iter()
+        .filter(|replacement| replacement.original == "e")
+        .map(|replacement| tokenize_molecule(&replacement.replacement).len())
+        .max()
+        .ok_or("no replacement for 'e'")?;
+    let rn = molecule.iter().filter(|&m| m == "Rn").count();
+    let y = molecule.iter().filter(|&m| m == "Y").count();
+    let ar = molecule.iter().filter(|&m| m == "Ar").count();
+    if rn != ar {
+        return Err(format!("unbalanced molecule: {rn} Rn but {ar} Ar").into());
+    }
+    (molecule.len() + 1)
+        .checked_sub(max_e + rn + ar + y * 2)
+        .ok_or_else(|| "the molecule cannot be made from 'e'".into())
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 19;
+    const INPUT_SIZE: usize = 200;
+
+    type Input = (Vec<Replacement>, Vec<String>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (replacements, molecule) = input
+            .trim_end()
+            .split_once("\n\n")
+            .ok_or("missing medicine molecule")?;
+        let replacements = LineParser::new().parse_with(replacements, parse_replacement)?;
+        Ok((replacements, tokenize_molecule(molecule)))
+    }
+
+    fn part1((replacements, molecule): &Self::Input) -> Result<usize> {
+        Ok(create_unique_modifications(molecule, replacements).len())
+    }
+
+    fn part2((replacements, molecule): &Self::Input) -> Result<usize> {
+        bfs_with_cheating(molecule, replacements)
+    }
+
+    /// Generates replacements shaped like the real ones and a molecule made from `e` in about
+    /// `size` steps.
+    ///
+    /// Like in the real input, `Rn`, `Y` and `Ar` are never replaced and only appear in
+    /// replacements like `X => XRnXAr` or `X => XRnXYXAr`, which is what `bfs_with_cheating`
+    /// relies on.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const ELEMENTS: [&str; 12] = [
+            "Al", "B", "Ca", "F", "H", "Mg", "N", "O", "P", "Si", "Th", "Ti",
+        ];
+
+        let mut replacements = Vec::new();
+        for _ in 0..3 {
+            replacements.push(("e", vec![*rng.choose(&ELEMENTS), *rng.choose(&ELEMENTS)]));
+        }
+        for original in ELEMENTS {
+            for _ in 0..rng.range(2..=4) {
+                let shape = rng.range(0..=9);
+                let mut element = || *rng.choose(&ELEMENTS);
+                let replacement = match shape {
+                    0..=6 => vec![element(), element()],
+                    7 | 8 => vec![element(), "Rn", element(), "Ar"],
+                    _ => vec![element(), "Rn", element(), "Y", element(), "Ar"],
+                };
+                replacements.push((original, replacement));
+            }
+        }
+
+        let mut molecule = rng.choose(&replacements[..3]).1.clone();
+        for _ in 0..size {
+            let positions: Vec<_> = (0..molecule.len())
+                .filter(|&i| ELEMENTS.contains(&molecule[i]))
+                .collect();
+            let position = *rng.choose(&positions);
+            let candidates: Vec<_> = replacements
+                .iter()
+                .filter(|(original, _)| *original == molecule[position])
+                .collect();
+            let replacement = &rng.choose(&candidates).1;
+            molecule.splice(position..=position, replacement.iter().copied());
+        }
+
+        let mut input: String = replacements
+            .iter()
+            .map(|(original, replacement)| format!("{original} => {}\n", replacement.concat()))
+            .collect();
+        input.push('\n');
+        input.push_str(&molecule.concat());
+        input.push('\n');
+        Some(input)
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::differential;
+
+    #[test]
+    fn test_bfs_with_cheating() {
+        let replacements = vec![
+            parse_replacement("e => H").unwrap(),
+            parse_replacement("e => O").unwrap(),
+            parse_replacement("H => HO").unwrap(),
+            parse_replacement("H => OH").unwrap(),
+            parse_replacement("O => HH").unwrap(),
+        ];
+
+        let molecule = vec!["H".to_string(), "O".to_string(), "H".to_string()];
+        assert_eq!(bfs_with_cheating(&molecule, &replacements).unwrap(), 3);
+
+        let molecule = vec![
+            "H".to_string(),
+            "O".to_string(),
+            "H".to_string(),
+            "O".to_string(),
+            "H".to_string(),
+            "O".to_string(),
+        ];
+        assert_eq!(bfs_with_cheating(&molecule, &replacements).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_count_steps_errors() {
+        let unbalanced = Day19::parse("e => H\nH => HO\n\nHRnHOHOHOHOHOHO").unwrap();
+        assert_eq!(
+            Day19::part2(&unbalanced).unwrap_err().to_string(),
+            "unbalanced molecule: 1 Rn but 0 Ar"
+        );
+        let without_e = Day19::parse("H => HO\n\nHOHOHOHOHOHOHOHO").unwrap();
+        assert_eq!(
+            Day19::part2(&without_e).unwrap_err().to_string(),
+            "no replacement for 'e'"
+        );
+        let too_short = Day19::parse("e => HOHOHOHOHOHO\n\nHRnHArYHYHYHYH").unwrap();
+        assert!(Day19::part2(&too_short).is_err());
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            for steps in [3, 40] {
+                let input = Day19::generate(&mut Rng::new(seed), steps).unwrap();
+                let parsed = Day19::parse(&input).unwrap();
+                // one step from `e` and one per expansion
+                assert_eq!(Day19::part2(&parsed).unwrap(), steps + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_differential() {
+        // the breadth-first search gets out of hand after a few more steps
+        differential::check::<Day19, _>(
+            1..=4,
+            5,
+            |(replacements, molecule)| bfs(molecule, replacements),
+            |(replacements, molecule)| count_steps(molecule, replacements).ok(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(super::Day19: hoh, hohoho);
+}
@@ -0,0 +1,192 @@
+use common::progress;
+use common::Result;
+
+/// The number of additions of the sieve between two progress reports.
+const ADDITIONS_PER_REPORT: u32 = 1 << 16;
+
+/// Sieves the sums of the divisors of all numbers up to `max`, reporting the progress.
+///
+/// # Errors
+///
+/// Returns an `Err` if the sieve was cancelled or ran out of time.
+pub fn create_sums_of_divisors(max: u32) -> Result<Vec<u32>> {
+    let reporter = progress::current();
+    reporter.check()?;
+    let mut sums_of_divisors = vec![0; max as usize + 1];
+    // Small numbers have the most multiples, so the reports follow the additions rather than the
+    // numbers.
+    let mut additions = 0_u32;
+    for i in 1..=max {
+        for j in (i..=max).step_by(i as usize) {
+            sums_of_divisors[j as usize] += i;
+            additions = additions.wrapping_add(1);
+            if additions.is_multiple_of(ADDITIONS_PER_REPORT) {
+                reporter.report("divisors", u64::from(i), Some(u64::from(max)))?;
+            }
+        }
+    }
+    Ok(sums_of_divisors)
+}
+
+fn sigma(n: u32) -> u32 {
+    (1..=n).filter(|i| n.is_multiple_of(*i)).sum()
+}
+
+fn sigma_with_cache(n: u32, cache: &[u32]) -> u32 {
+    cache[n as usize]
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn sigma_upper_bound(n: u32) -> u32 {
+    // according to Ramanujan, sigma(n) < e^γ * n * log(log(n)) for large enough n
+    // (assuming Riemann hypothesis)
+    const EULER_MASCHERONI: f64 = 0.577_215_664_901_532_9;
+    let n = f64::from(n);
+    (n * EULER_MASCHERONI * n.ln() * n.ln()).ceil() as u32
+}
+
+fn number_of_presents(house_number: u32) -> u32 {
+    sigma(house_number) * 10
+}
+
+fn number_of_presents_with_cache(house_number: u32, cache: &[u32]) -> u32 {
+    sigma_with_cache(house_number, cache) * 10
+}
+
+#[allow(clippy::maybe_infinite_iter)]
+#[must_use]
+pub fn find_house_with_at_least_x_presents(x: u32, cache: &[u32]) -> Option<u32> {
+    // Robin's theorem: n is large enough if n > 5040
+    if x > 5040 {
+        let sum_of_divisors = x / 10;
+
+        // find a a lower bound for the house number using the upper bound of sigma
+        // i.e. find the smallest n such that sigma_upper_bound(n) >= sum_of_divisors
+        // using binary search
+        let mut left = 1;
+        let mut right = x;
+        while left < right {
+            let mid = (left + right) / 2;
+            if sigma_upper_bound(mid) >= sum_of_divisors {
+                right = mid;
+            } else {
+                left = mid + 1;
+            }
+        }
+        (left..).find(|house_number| number_of_presents_with_cache(*house_number, cache) >= x)
+    } else {
+        (1..).find(|house_number| number_of_presents(*house_number) >= x)
+    }
+}
+
+/// Parses the number of presents of the puzzle input.
+///
+/// # Errors
+///
+/// Returns an `Err` if the input is not a positive number.
+pub fn parse_presents(input: &str) -> Result<u32> {
+    let presents = input.trim().parse()?;
+    if presents == 0 {
+        return Err("the number of presents must be positive".into());
+    }
+    Ok(presents)
+}
+
+/// The lowest house number that gets at least `presents` presents.
+///
+/// # Errors
+///
+/// Returns an `Err` if no house gets enough presents or the sieve was stopped.
+pub fn lowest_house(presents: u32) -> Result<u32> {
+    // heuristic that works
+    let cache = create_sums_of_divisors(presents / 10)?;
+    find_house_with_at_least_x_presents(presents, &cache)
+        .ok_or_else(|| "no house gets enough presents".into())
+}
+
+/// The lowest house number that gets at least the number of presents of the puzzle input.
+///
+/// # Errors
+///
+/// Returns an `Err` if the input is not a positive number or no house gets enough presents.
+pub fn part1(input: &str) -> Result<u32> {
+    lowest_house(parse_presents(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use common::differential;
+    use common::progress::{CancelToken, Progress};
+
+    #[test]
+    fn test_number_of_presents() {
+        assert_eq!(number_of_presents(1), 10);
+        assert_eq!(number_of_presents(2), 30);
+        assert_eq!(number_of_presents(3), 40);
+        assert_eq!(number_of_presents(4), 70);
+        assert_eq!(number_of_presents(5), 60);
+        assert_eq!(number_of_presents(6), 120);
+        assert_eq!(number_of_presents(7), 80);
+        assert_eq!(number_of_presents(8), 150);
+        assert_eq!(number_of_presents(9), 130);
+    }
+
+    #[test]
+    fn test_number_of_presents_with_cache() {
+        let cache = create_sums_of_divisors(100).unwrap();
+        assert_eq!(number_of_presents_with_cache(1, &cache), 10);
+        assert_eq!(number_of_presents_with_cache(2, &cache), 30);
+        assert_eq!(number_of_presents_with_cache(3, &cache), 40);
+        assert_eq!(number_of_presents_with_cache(4, &cache), 70);
+        assert_eq!(number_of_presents_with_cache(5, &cache), 60);
+        assert_eq!(number_of_presents_with_cache(6, &cache), 120);
+        assert_eq!(number_of_presents_with_cache(7, &cache), 80);
+        assert_eq!(number_of_presents_with_cache(8, &cache), 150);
+        assert_eq!(number_of_presents_with_cache(9, &cache), 130);
+    }
+
+    #[test]
+    fn test_timeout() {
+        let result = Progress::new()
+            .with_timeout(Some(Duration::ZERO))
+            .run(|| create_sums_of_divisors(1 << 16));
+        assert_eq!(result, Err("timed out after 0ms".into()));
+    }
+
+    #[test]
+    fn test_cancel() {
+        // cancels on the first report, so the sieve has to stop at the second
+        let token = CancelToken::new();
+        let cancel = token.clone();
+        let result = Progress::new()
+            .with_token(token)
+            .with_interval(Duration::ZERO)
+            .on_update(move |_| cancel.cancel())
+            .run(|| create_sums_of_divisors(1 << 16));
+        assert_eq!(result, Err("cancelled".into()));
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("150\n"), Ok(8));
+        assert!(part1("0\n").is_err());
+    }
+
+    #[test]
+    fn test_differential() {
+        let disagreement = differential::find(
+            |rng, size| format!("{}\n", rng.range(size / 2..=size)),
+            |input| input.trim().parse::<u32>().ok(),
+            [100, 1_000, 10_000, 100_000],
+            5,
+            |&x| (1..).find(|&house_number| number_of_presents(house_number) >= x),
+            |&x| find_house_with_at_least_x_presents(x, &create_sums_of_divisors(x / 10).ok()?),
+        );
+        if let Some(disagreement) = disagreement {
+            panic!("{disagreement}");
+        }
+    }
+}
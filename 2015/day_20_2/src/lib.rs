@@ -0,0 +1,144 @@
+use common::generate::Rng;
+use common::progress;
+use common::solution::{Result, Solution};
+pub use day_20_1::part1;
+
+/// The number of additions of the sieve between two progress reports.
+const ADDITIONS_PER_REPORT: u32 = 1 << 16;
+
+/// Sieves the sums of the divisors of all numbers up to `max`, counting only the divisors `d` of
+/// `n` with `n / d < limit` as the elves stop after `limit - 1` houses.
+///
+/// # Errors
+///
+/// Returns an `Err` if the sieve was cancelled or ran out of time.
+pub fn create_sums_of_divisors(max: u32, limit: u32) -> Result<Vec<u32>> {
+    let reporter = progress::current();
+    reporter.check()?;
+    let mut sums_of_divisors = vec![0; max as usize + 1];
+    // reports follow the additions like in the sieve of part 1
+    let mut additions = 0_u32;
+    for i in 1..=max {
+        for j in (1..limit).map(|k| k * i).take_while(|&j| j <= max) {
+            sums_of_divisors[j as usize] += i;
+            additions = additions.wrapping_add(1);
+            if additions.is_multiple_of(ADDITIONS_PER_REPORT) {
+                reporter.report("divisors", u64::from(i), Some(u64::from(max)))?;
+            }
+        }
+    }
+    Ok(sums_of_divisors)
+}
+
+fn sigma_prime_with_cache(n: u32, cache: &[u32]) -> u32 {
+    cache[n as usize]
+}
+
+fn number_of_presents_with_cache(house_number: u32, cache: &[u32]) -> u32 {
+    sigma_prime_with_cache(house_number, cache) * 11
+}
+
+#[allow(clippy::maybe_infinite_iter)]
+#[must_use]
+pub fn find_house_with_at_least_x_presents(x: u32, cache: &[u32]) -> Option<u32> {
+    (1..).find(|house_number| number_of_presents_with_cache(*house_number, cache) >= x)
+}
+
+/// The lowest house number that gets at least the number of presents of the puzzle input from the
+/// lazy elves.
+///
+/// # Errors
+///
+/// Returns an `Err` if the input is not a positive number or no house gets enough presents.
+pub fn part2(input: &str) -> Result<u32> {
+    Day20::part2(&Day20::parse(input)?)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 20;
+    const INPUT_SIZE: usize = 33_100_000;
+
+    type Input = u32;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<u32> {
+        day_20_1::parse_presents(input)
+    }
+
+    fn part1(presents: &u32) -> Result<u32> {
+        day_20_1::lowest_house(*presents)
+    }
+
+    fn part2(presents: &u32) -> Result<u32> {
+        // house n gets at least 11 * n presents, so the cache always contains the answer
+        let cache = create_sums_of_divisors(presents.div_ceil(11), 50)?;
+        find_house_with_at_least_x_presents(*presents, &cache)
+            .ok_or_else(|| "no house gets enough presents".into())
+    }
+
+    /// Generates a number of presents between `size / 2` and `size`.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = u32::try_from(size).unwrap_or(u32::MAX).max(1);
+        Some(format!("{}\n", rng.range(size.div_ceil(2)..=size)))
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use common::progress::{CancelToken, Progress};
+
+    #[test]
+    fn test_number_of_presents_with_cache() {
+        let cache = create_sums_of_divisors(100, 50).unwrap();
+        assert_eq!(number_of_presents_with_cache(1, &cache), 11);
+        assert_eq!(number_of_presents_with_cache(2, &cache), 33);
+        assert_eq!(number_of_presents_with_cache(6, &cache), 132);
+        assert_eq!(
+            number_of_presents_with_cache(51, &cache),
+            11 * (3 + 17 + 51)
+        );
+    }
+
+    #[test]
+    fn test_timeout() {
+        let result = Progress::new()
+            .with_timeout(Some(Duration::ZERO))
+            .run(|| create_sums_of_divisors(1 << 16, 50));
+        assert_eq!(result, Err("timed out after 0ms".into()));
+    }
+
+    #[test]
+    fn test_cancel() {
+        // cancels on the first report, so the sieve has to stop at the second
+        let token = CancelToken::new();
+        let cancel = token.clone();
+        let result = Progress::new()
+            .with_token(token)
+            .with_interval(Duration::ZERO)
+            .on_update(move |_| cancel.cancel())
+            .run(|| create_sums_of_divisors(1 << 16, 50));
+        assert_eq!(result, Err("cancelled".into()));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(Day20::parse("0").is_err());
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(super::Day20: house_six, house_eight);
+}
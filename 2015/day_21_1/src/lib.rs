@@ -1,4 +1,7 @@
+use common::BitSet;
+
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ItemCategory {
     Weapon,
     Armor,
@@ -6,6 +9,7 @@ pub enum ItemCategory {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ItemSpec {
     pub name: String,
     pub cost: u32,
@@ -14,12 +18,14 @@ pub struct ItemSpec {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Item {
     pub spec: ItemSpec,
     pub category: ItemCategory,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EntityStats {
     pub hit_points: u32,
     pub damage: u32,
@@ -151,44 +157,39 @@ pub mod parsers {
     }
 }
 
-fn item_combinations() -> impl Iterator<Item = u32> {
-    0..1 << 16
-}
-
-fn combination_is_valid(mask: u32, items: &[Item]) -> bool {
+fn combination_is_valid(mask: &BitSet, items: &[Item]) -> bool {
     let mut num_weapons = 0;
     let mut num_armor = 0;
     let mut num_rings = 0;
 
-    for (i, item) in items.iter().enumerate() {
-        if mask & (1 << i) != 0 {
-            match item.category {
-                ItemCategory::Weapon => num_weapons += 1,
-                ItemCategory::Armor => num_armor += 1,
-                ItemCategory::Ring => num_rings += 1,
-            }
+    for i in mask.iter() {
+        match items[i].category {
+            ItemCategory::Weapon => num_weapons += 1,
+            ItemCategory::Armor => num_armor += 1,
+            ItemCategory::Ring => num_rings += 1,
         }
     }
 
     num_weapons == 1 && num_armor <= 1 && num_rings <= 2
 }
 
-pub fn valid_item_combinations(items: &[Item]) -> impl Iterator<Item = u32> + '_ {
-    item_combinations().filter(move |&mask| combination_is_valid(mask, items))
+pub fn valid_item_combinations(items: &[Item]) -> impl Iterator<Item = BitSet> + '_ {
+    BitSet::full(items.len())
+        .subsets()
+        .filter(move |mask| combination_is_valid(mask, items))
 }
 
 #[must_use]
-pub fn get_stats(mask: u32, items: &[Item]) -> (u32, u32, u32) {
+pub fn get_stats(mask: &BitSet, items: &[Item]) -> (u32, u32, u32) {
     let mut cost = 0;
     let mut damage = 0;
     let mut armor = 0;
 
-    for (i, item) in items.iter().enumerate() {
-        if mask & (1 << i) != 0 {
-            cost += item.spec.cost;
-            damage += item.spec.damage;
-            armor += item.spec.armor;
-        }
+    for i in mask.iter() {
+        let item = &items[i].spec;
+        cost += item.cost;
+        damage += item.damage;
+        armor += item.armor;
     }
 
     (cost, damage, armor)
@@ -199,8 +200,8 @@ pub fn fight_result(player: &EntityStats, boss: &EntityStats) -> bool {
     let player_damage = player.damage.checked_sub(boss.armor).unwrap_or(1).max(1);
     let boss_damage = boss.damage.checked_sub(player.armor).unwrap_or(1).max(1);
 
-    let player_turns = (boss.hit_points + player_damage - 1) / player_damage;
-    let boss_turns = (player.hit_points + boss_damage - 1) / boss_damage;
+    let player_turns = boss.hit_points.div_ceil(player_damage);
+    let boss_turns = player.hit_points.div_ceil(boss_damage);
 
     player_turns <= boss_turns
 }
@@ -227,6 +228,6 @@ mod tests {
             damage: 7,
             armor: 2,
         };
-        assert_eq!(fight_result(&player, &boss), true);
+        assert!(fight_result(&player, &boss));
     }
 }
@@ -1,35 +1,41 @@
 //! Advent of code 2015 day 21 part 1
 
-use std::{collections::HashMap, io::BufRead};
+use std::collections::HashMap;
 
+use common::error::parse_complete;
+use common::LineParser;
 use day_21_1::{
     fight_result, get_stats, parsers, valid_item_combinations, EntityStats, ITEM_STRING,
 };
 
-fn main() {
-    let file = std::fs::File::open("input/input.txt").unwrap();
-    let reader = std::io::BufReader::new(file);
-    let boss_config: HashMap<_, _> = reader
-        .lines()
-        .map(|line| {
-            parsers::key_value_line(&line.unwrap())
-                .map(|(_, (k, v))| (k.to_string(), v))
-                .unwrap()
-        })
+fn main() -> common::Result<()> {
+    let input = common::input::load(env!("CARGO_MANIFEST_DIR"), 2015, 21)?;
+    let boss_config: HashMap<_, _> = LineParser::new()
+        .parse_with(&input, |line| parse_complete(line, parsers::key_value_line))?
+        .into_iter()
         .collect();
+    let stat = |key| {
+        boss_config
+            .get(key)
+            .copied()
+            .ok_or_else(|| format!("missing boss stat: {key}"))
+    };
 
     let boss = EntityStats {
-        hit_points: boss_config["Hit Points"],
-        damage: boss_config["Damage"],
-        armor: boss_config["Armor"],
+        hit_points: stat("Hit Points")?,
+        damage: stat("Damage")?,
+        armor: stat("Armor")?,
     };
 
     println!("Boss: {boss:?}");
 
-    let (_, items) = parsers::items(ITEM_STRING).unwrap();
+    let items = parse_complete(ITEM_STRING, parsers::items)?;
 
     let (mask, (cost, _, _)) = valid_item_combinations(&items)
-        .map(|mask| (mask, get_stats(mask, &items)))
+        .map(|mask| {
+            let stats = get_stats(&mask, &items);
+            (mask, stats)
+        })
         .filter(|(_, (_, damage, armor))| {
             fight_result(
                 &EntityStats {
@@ -41,13 +47,12 @@ fn main() {
             )
         })
         .min_by_key(|(_, (cost, _, _))| *cost)
-        .unwrap();
+        .ok_or("the boss cannot be beaten")?;
 
     println!("Minimum cost: {cost}");
     println!("Items:");
-    for (i, item) in items.iter().enumerate() {
-        if mask & (1 << i) != 0 {
-            println!("  - {}", item.spec.name);
-        }
+    for i in mask.iter() {
+        println!("  - {}", items[i].spec.name);
     }
+    Ok(())
 }
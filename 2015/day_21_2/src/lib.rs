@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use common::error::parse_complete;
+use common::generate::Rng;
+use common::solution::{Answer, Result, Solution, Work};
+use common::LineParser;
+use day_21_1::{
+    fight_result, get_stats, parsers, valid_item_combinations, EntityStats, ITEM_STRING,
+};
+
+const PLAYER_HIT_POINTS: u32 = 100;
+
+/// The loadouts with which the fight ends in the given way, as their cost and item names.
+fn loadouts_by_outcome(boss: &EntityStats, player_wins: bool) -> Result<Vec<Answer<u32>>> {
+    let items = parse_complete(ITEM_STRING, parsers::items)?;
+    Ok(valid_item_combinations(&items)
+        .filter_map(|mask| {
+            let (cost, damage, armor) = get_stats(&mask, &items);
+            let player = EntityStats {
+                hit_points: PLAYER_HIT_POINTS,
+                damage,
+                armor,
+            };
+            (fight_result(&player, boss) == player_wins).then(|| {
+                let names = mask.iter().map(|i| &items[i].spec.name);
+                Answer::new(cost).with_work(Work::items("items", names))
+            })
+        })
+        .collect())
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 21;
+    const INPUT_SIZE: usize = 100;
+
+    type Input = EntityStats;
+    type Answer1 = Answer<u32>;
+    type Answer2 = Answer<u32>;
+
+    fn parse(input: &str) -> Result<EntityStats> {
+        let boss_config: HashMap<_, _> = LineParser::new()
+            .parse_with(input, |line| parse_complete(line, parsers::key_value_line))?
+            .into_iter()
+            .collect();
+        let stat = |key| {
+            boss_config
+                .get(key)
+                .copied()
+                .ok_or_else(|| format!("missing boss stat: {key}"))
+        };
+
+        Ok(EntityStats {
+            hit_points: stat("Hit Points")?,
+            damage: stat("Damage")?,
+            armor: stat("Armor")?,
+        })
+    }
+
+    fn part1(boss: &EntityStats) -> Result<Answer<u32>> {
+        loadouts_by_outcome(boss, true)?
+            .into_iter()
+            .min_by_key(|answer| answer.value)
+            .ok_or_else(|| "the boss cannot be beaten".into())
+    }
+
+    fn part2(boss: &EntityStats) -> Result<Answer<u32>> {
+        loadouts_by_outcome(boss, false)?
+            .into_iter()
+            .max_by_key(|answer| answer.value)
+            .ok_or_else(|| "the boss cannot win".into())
+    }
+
+    /// Generates a boss with between `size / 2` and `size` hit points, who hits hard enough to
+    /// beat a player with nothing but a dagger, so that there is a losing loadout for part 2.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const DAGGER_DAMAGE: u32 = 4;
+
+        let size = u32::try_from(size).unwrap_or(u32::MAX).max(1);
+        let hit_points = rng.range(size.div_ceil(2)..=size);
+        let armor = rng.range(0..=3);
+        // the player strikes first, so the boss has to need fewer turns than the player
+        let player_turns = hit_points.div_ceil(DAGGER_DAMAGE.saturating_sub(armor).max(1));
+        let damage = (1..PLAYER_HIT_POINTS)
+            .find(|damage| PLAYER_HIT_POINTS.div_ceil(*damage) < player_turns)
+            .unwrap_or(PLAYER_HIT_POINTS);
+        let damage = rng.range(damage..=damage + 3);
+        Some(format!(
+            "Hit Points: {hit_points}\nDamage: {damage}\nArmor: {armor}\n"
+        ))
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day21() {
+        let boss = Day21::parse("Hit Points: 12\nDamage: 7\nArmor: 2\n").unwrap();
+        assert_eq!(boss.hit_points, 12);
+        assert_eq!(boss.damage, 7);
+        assert_eq!(boss.armor, 2);
+        let cheapest = Day21::part1(&boss).unwrap();
+        assert_eq!(cheapest.value, 8);
+        assert_eq!(cheapest.work.unwrap().to_string(), "items: Dagger");
+        assert!(Day21::parse("Hit Points: 12\nDamage: 7\n").is_err());
+    }
+}
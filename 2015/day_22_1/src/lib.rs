@@ -1,7 +1,11 @@
+use common::progress;
+use common::search::branch_and_bound;
+use common::Result;
 use itertools::{repeat_n, Itertools};
 use std::collections::HashMap;
 
 #[derive(Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Spell {
     MagicMissile,
     Drain,
@@ -10,19 +14,68 @@ pub enum Spell {
     Recharge,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+impl std::fmt::Display for Spell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::MagicMissile => "Magic Missile",
+            Self::Drain => "Drain",
+            Self::Shield => "Shield",
+            Self::Poison => "Poison",
+            Self::Recharge => "Recharge",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlayerStats {
     pub hit_points: i32,
     pub mana: i32,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BossStats {
     pub hit_points: i32,
     pub damage: i32,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+impl TryFrom<&str> for BossStats {
+    type Error = common::Error;
+
+    /// Parses the boss description, e.g. `Hit Points: 51` and `Damage: 9` on separate lines.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let boss_config = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let invalid =
+                    |message: String| common::Error::new(message).with_line(i + 1).with_text(line);
+                let (key, value) = line
+                    .split_once(": ")
+                    .ok_or_else(|| invalid("invalid boss stat".to_string()))?;
+                let value = value
+                    .parse()
+                    .map_err(|_| invalid(format!("invalid value for {key}: {value}")))?;
+                Ok((key, value))
+            })
+            .collect::<Result<HashMap<_, _>, common::Error>>()?;
+        let stat = |key| {
+            boss_config
+                .get(key)
+                .copied()
+                .ok_or_else(|| format!("missing boss stat: {key}"))
+        };
+
+        Ok(Self {
+            hit_points: stat("Hit Points")?,
+            damage: stat("Damage")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Player {
     stats: PlayerStats,
     armor: i32,
@@ -31,7 +84,7 @@ pub struct Player {
     recharge_timer: i32,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Boss {
     stats: BossStats,
 }
@@ -189,33 +242,68 @@ fn simulate(rules: &Rules, player: &PlayerStats, boss: &BossStats, spells: &[&Sp
     Outcome::Undecided(player, boss, total_cost)
 }
 
-#[must_use]
+/// Simulates every sequence of `max_depth` spells to find the cheapest win.
+///
+/// This takes a while to compute, so it reports its progress.
+///
+/// # Errors
+///
+/// Returns an `Err` if the search was cancelled or ran out of time.
 pub fn find_cheapest_win_exhaustive(
     rules: &Rules,
     player: &PlayerStats,
     boss: &BossStats,
     max_depth: usize,
-) -> Option<i32> {
-    // this takes a while to compute
-    spell_combinations(max_depth)
-        .map(|spells| simulate(rules, player, boss, &spells))
-        .filter_map(|outcome| {
-            if let Outcome::PlayerWins(cost) = outcome {
-                Some(cost)
-            } else {
-                None
-            }
-        })
-        .min()
+) -> Result<Option<i32>> {
+    let reporter = progress::current();
+    let total = u32::try_from(max_depth)
+        .ok()
+        .and_then(|depth| 5_u64.checked_pow(depth));
+    let mut cheapest: Option<i32> = None;
+    for (tried, spells) in (0_u64..).zip(spell_combinations(max_depth)) {
+        if tried % 4096 == 0 {
+            reporter.report("spell sequences", tried, total)?;
+        }
+        if let Outcome::PlayerWins(cost) = simulate(rules, player, boss, &spells) {
+            cheapest = Some(cheapest.map_or(cost, |cheapest| cheapest.min(cost)));
+        }
+    }
+    Ok(cheapest)
 }
 
-#[must_use]
-pub fn find_cheapest_spell_dfs(
+/// A node of the search for the cheapest win, taken after each spell.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Fight {
+    Ongoing(Player, Boss),
+    Won,
+}
+
+/// Casts a spell in an ongoing fight, returning the fight afterwards and the mana spent.
+fn cast(rules: &Rules, fight: &Fight, spell: &Spell) -> Option<(Fight, i32)> {
+    let Fight::Ongoing(player, boss) = fight else {
+        return None;
+    };
+    match simulate_single(rules, player, boss, spell) {
+        Outcome::PlayerWins(cost) => Some((Fight::Won, cost)),
+        Outcome::Undecided(player, boss, cost) => Some((Fight::Ongoing(player, boss), cost)),
+        Outcome::BossWins | Outcome::Invalid => None,
+    }
+}
+
+/// Finds the cheapest sequence of at most `max_depth` spells that wins the fight, returning its
+/// mana cost and the spells.
+///
+/// The search reports the fights it expanded as its progress.
+///
+/// # Errors
+///
+/// Returns an `Err` if the search was cancelled or ran out of time.
+pub fn find_cheapest_spells(
     rules: &Rules,
     player: &PlayerStats,
     boss: &BossStats,
     max_depth: usize,
-) -> Option<i32> {
+) -> Result<Option<(i32, Vec<&'static Spell>)>> {
     let player = Player {
         stats: player.clone(),
         armor: 0,
@@ -228,41 +316,82 @@ pub fn find_cheapest_spell_dfs(
         stats: boss.clone(),
     };
 
-    let mut stack = Vec::new();
-    for spell in SPELLS {
-        stack.push((player.clone(), boss.clone(), spell, 0, 1));
-    }
-
-    let mut best_result: Option<i32> = None;
-    while let Some((player, boss, spell, cost, depth)) = stack.pop() {
-        match simulate_single(rules, &player, &boss, &spell) {
-            Outcome::PlayerWins(new_cost) => {
-                let cost = cost + new_cost;
-                best_result = best_result.map(|old| old.min(cost)).or(Some(cost));
-            }
-            Outcome::Undecided(player, boss, new_cost)
-                if depth < max_depth && (best_result.is_none() || Some(new_cost) < best_result) =>
-            {
-                for spell in SPELLS {
-                    stack.push((
-                        player.clone(),
-                        boss.clone(),
-                        spell,
-                        cost + new_cost,
-                        depth + 1,
-                    ));
-                }
-            }
-            _ => (),
+    // the search cannot fail, so once stopped it gets no more successors and the error is kept
+    let reporter = progress::current();
+    let mut expanded = 0_u64;
+    let mut stopped = Ok(());
+    let successors = |fight: &Fight| {
+        if expanded.is_multiple_of(4096) {
+            stopped = stopped
+                .clone()
+                .and_then(|()| reporter.report("fights", expanded, None));
         }
-    }
-    best_result
+        expanded += 1;
+        if stopped.is_err() {
+            return Vec::new();
+        }
+        SPELLS
+            .iter()
+            .filter_map(|spell| cast(rules, fight, spell))
+            .collect::<Vec<_>>()
+    };
+    let path = branch_and_bound(
+        Fight::Ongoing(player, boss),
+        successors,
+        |fight| *fight == Fight::Won,
+        max_depth,
+    )
+    .path;
+    stopped?;
+    let Some(path) = path else {
+        return Ok(None);
+    };
+
+    // the search only keeps the fights, so find the spell that leads from each to the next
+    let spells = path
+        .nodes
+        .windows(2)
+        .map(|pair| {
+            SPELLS
+                .iter()
+                .find(|spell| cast(rules, &pair[0], spell).is_some_and(|(next, _)| next == pair[1]))
+        })
+        .collect::<Option<_>>();
+    Ok(spells.map(|spells| (path.cost, spells)))
+}
+
+/// The mana cost of the cheapest win found by [`find_cheapest_spells`].
+///
+/// # Errors
+///
+/// Returns an `Err` if the search was cancelled or ran out of time.
+pub fn find_cheapest_spell_dfs(
+    rules: &Rules,
+    player: &PlayerStats,
+    boss: &BossStats,
+    max_depth: usize,
+) -> Result<Option<i32>> {
+    Ok(find_cheapest_spells(rules, player, boss, max_depth)?.map(|(cost, _)| cost))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_boss_stats() {
+        let boss = BossStats::try_from("Hit Points: 51\nDamage: 9\n").unwrap();
+        assert_eq!(
+            boss,
+            BossStats {
+                hit_points: 51,
+                damage: 9
+            }
+        );
+        assert!(BossStats::try_from("Hit Points: 51\n").is_err());
+        assert!(BossStats::try_from("Hit Points: x\nDamage: 9\n").is_err());
+    }
+
     fn rules() -> Rules {
         let mut spell_costs = HashMap::new();
         spell_costs.insert(Spell::MagicMissile, 53);
@@ -412,6 +541,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_find_cheapest_spell_dfs() {
+        let player = PlayerStats {
+            hit_points: 10,
+            mana: 250,
+        };
+        let boss = BossStats {
+            hit_points: 13,
+            damage: 8,
+        };
+        assert_eq!(
+            find_cheapest_spell_dfs(&rules(), &player, &boss, 8),
+            Ok(Some(173 + 53))
+        );
+        assert_eq!(
+            find_cheapest_spell_dfs(&rules(), &player, &boss, 1),
+            Ok(None)
+        );
+        assert_eq!(
+            find_cheapest_spells(&rules(), &player, &boss, 8),
+            Ok(Some((173 + 53, vec![&Spell::Poison, &Spell::MagicMissile])))
+        );
+    }
+
     #[test]
     fn test_simulate_example() {
         let player = PlayerStats {
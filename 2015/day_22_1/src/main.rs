@@ -18,16 +18,16 @@ fn rules() -> Rules {
     }
 }
 
-fn main() {
+fn main() -> common::Result<()> {
     let player = PlayerStats {
         hit_points: 50,
         mana: 500,
     };
-    let boss = BossStats {
-        hit_points: 51,
-        damage: 9,
-    };
+    let input = common::input::load(env!("CARGO_MANIFEST_DIR"), 2015, 22)?;
+    let boss = BossStats::try_from(input.as_str())?;
 
-    let min_mana = find_cheapest_spell_dfs(&rules(), &player, &boss, 8).unwrap();
+    let min_mana =
+        find_cheapest_spell_dfs(&rules(), &player, &boss, 8)?.ok_or("the boss cannot be beaten")?;
     println!("Minimum mana: {min_mana}");
+    Ok(())
 }
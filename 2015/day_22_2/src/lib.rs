@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use common::generate::Rng;
+use common::solution::{Answer, Result, Solution, Work};
+use day_22_1::{find_cheapest_spells, BossStats, PlayerStats, Rules, Spell};
+
+const PLAYER: PlayerStats = PlayerStats {
+    hit_points: 50,
+    mana: 500,
+};
+
+const MAX_DEPTH: usize = 8;
+
+fn rules(start_of_turn_damage: i32) -> Rules {
+    let mut spell_costs = HashMap::new();
+    spell_costs.insert(Spell::MagicMissile, 53);
+    spell_costs.insert(Spell::Drain, 73);
+    spell_costs.insert(Spell::Shield, 113);
+    spell_costs.insert(Spell::Poison, 173);
+    spell_costs.insert(Spell::Recharge, 229);
+
+    Rules {
+        spell_costs,
+        start_of_turn_damage,
+    }
+}
+
+fn cheapest_win(boss: &BossStats, start_of_turn_damage: i32) -> Result<Answer<i32>> {
+    let (cost, spells) =
+        find_cheapest_spells(&rules(start_of_turn_damage), &PLAYER, boss, MAX_DEPTH)?
+            .ok_or("the boss cannot be beaten")?;
+    Ok(Answer::new(cost).with_work(Work::steps("spells", spells)))
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 22;
+    const INPUT_SIZE: usize = 51;
+
+    type Input = BossStats;
+    type Answer1 = Answer<i32>;
+    type Answer2 = Answer<i32>;
+
+    fn parse(input: &str) -> Result<BossStats> {
+        BossStats::try_from(input)
+    }
+
+    fn part1(boss: &BossStats) -> Result<Answer<i32>> {
+        cheapest_win(boss, 0)
+    }
+
+    fn part2(boss: &BossStats) -> Result<Answer<i32>> {
+        cheapest_win(boss, 1)
+    }
+
+    /// Generates a boss with between `size / 2` and `size` hit points.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = u32::try_from(size).unwrap_or(u32::MAX).max(1);
+        let hit_points = rng.range(size.div_ceil(2)..=size);
+        let damage = rng.range(5..=10);
+        Some(format!("Hit Points: {hit_points}\nDamage: {damage}\n"))
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use common::differential;
+    use common::progress::{CancelToken, Progress};
+    use day_22_1::{find_cheapest_spell_dfs, find_cheapest_win_exhaustive};
+
+    #[test]
+    fn test_differential() {
+        // the exhaustive search tries all 5^depth spell sequences, so only weak bosses can be
+        // compared
+        const DEPTH: usize = 6;
+        for start_of_turn_damage in [0, 1] {
+            let rules = rules(start_of_turn_damage);
+            differential::check::<Day22, _>(
+                [10, 20, 30],
+                3,
+                |boss| find_cheapest_win_exhaustive(&rules, &PLAYER, boss, DEPTH).unwrap(),
+                |boss| find_cheapest_spell_dfs(&rules, &PLAYER, boss, DEPTH).unwrap(),
+            );
+        }
+    }
+    #[test]
+    fn test_timeout() {
+        let boss = Day22::parse("Hit Points: 51\nDamage: 9\n").unwrap();
+        let result = Progress::new()
+            .with_timeout(Some(Duration::ZERO))
+            .run(|| Day22::part1(&boss));
+        assert_eq!(result, Err("timed out after 0ms".into()));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let boss = Day22::parse("Hit Points: 51\nDamage: 9\n").unwrap();
+        let token = CancelToken::new();
+        token.cancel();
+        let result = Progress::new()
+            .with_token(token)
+            .run(|| Day22::part2(&boss));
+        assert_eq!(result, Err("cancelled".into()));
+    }
+}
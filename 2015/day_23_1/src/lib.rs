@@ -1,6 +1,10 @@
 use std::collections::HashMap;
 
+use common::progress;
+use common::Result;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Register {
     A,
     B,
@@ -9,6 +13,7 @@ pub enum Register {
 pub type RegisterValue = u32;
 
 #[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Instruction {
     Hlf(Register),
     Tpl(Register),
@@ -90,14 +95,40 @@ mod parsers {
     }
 }
 
-impl<'a> TryFrom<&'a str> for Instruction {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
+impl TryFrom<&str> for Instruction {
+    type Error = common::Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        common::error::parse_complete(input, parsers::instruction)
+    }
+}
+
+impl std::fmt::Display for Register {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::A => write!(f, "a"),
+            Self::B => write!(f, "b"),
+        }
+    }
+}
 
-    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        parsers::instruction(input).map(|(_, c)| c)
+impl std::fmt::Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // offsets always carry a sign, like in the puzzle input
+        match self {
+            Self::Hlf(register) => write!(f, "hlf {register}"),
+            Self::Tpl(register) => write!(f, "tpl {register}"),
+            Self::Inc(register) => write!(f, "inc {register}"),
+            Self::Jmp(offset) => write!(f, "jmp {offset:+}"),
+            Self::Jie(register, offset) => write!(f, "jie {register}, {offset:+}"),
+            Self::Jio(register, offset) => write!(f, "jio {register}, {offset:+}"),
+        }
     }
 }
 
+/// The number of instructions run between two progress reports.
+const STEPS_PER_REPORT: u64 = 1 << 16;
+
 pub struct Processor<'a> {
     instructions: &'a [Instruction],
     program_counter: i32,
@@ -123,7 +154,7 @@ impl<'a> Processor<'a> {
                     Instruction::Inc(r) => *self.registers.entry(*r).or_insert(0) += 1,
                     Instruction::Jmp(o) => self.program_counter += o - 1,
                     Instruction::Jie(r, o) => {
-                        if self.registers.get(r).unwrap_or(&0) % 2 == 0 {
+                        if self.registers.get(r).unwrap_or(&0).is_multiple_of(2) {
                             self.program_counter += o - 1;
                         }
                     }
@@ -143,8 +174,22 @@ impl<'a> Processor<'a> {
         }
     }
 
-    pub fn run(&mut self) {
-        while !self.step() {}
+    /// Runs the program until it jumps out of its instructions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the run was cancelled or ran out of time, as a program can loop
+    /// forever.
+    pub fn run(&mut self) -> Result<()> {
+        let reporter = progress::current();
+        let mut steps = 0_u64;
+        while !self.step() {
+            steps += 1;
+            if steps.is_multiple_of(STEPS_PER_REPORT) {
+                reporter.report("instructions", steps, None)?;
+            }
+        }
+        Ok(())
     }
 
     #[must_use]
@@ -170,7 +215,31 @@ impl<'a> Processor<'a> {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use super::*;
+    use common::progress::Progress;
+    use proptest::prelude::*;
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        let register = prop_oneof![Just(Register::A), Just(Register::B)];
+        prop_oneof![
+            register.clone().prop_map(Instruction::Hlf),
+            register.clone().prop_map(Instruction::Tpl),
+            register.clone().prop_map(Instruction::Inc),
+            any::<i32>().prop_map(Instruction::Jmp),
+            (register.clone(), any::<i32>()).prop_map(|(r, o)| Instruction::Jie(r, o)),
+            (register, any::<i32>()).prop_map(|(r, o)| Instruction::Jio(r, o)),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_display_round_trip(instruction in instruction()) {
+            let printed = instruction.to_string();
+            prop_assert_eq!(Instruction::try_from(printed.as_str()), Ok(instruction));
+        }
+    }
 
     #[test]
     fn test_parse_instruction() {
@@ -247,7 +316,7 @@ mod tests {
             .collect();
 
         let mut processor = Processor::new(&instructions);
-        processor.run();
+        processor.run().unwrap();
         assert_eq!(processor.get_register(Register::A), 2);
     }
 
@@ -310,6 +379,19 @@ mod tests {
         assert_eq!(processor.program_counter(), Some(0));
     }
 
+    #[test]
+    fn test_run_endless_loop() {
+        let instructions = [
+            Instruction::Inc(Register::A),
+            Instruction::Jio(Register::A, 0),
+        ];
+        let mut processor = Processor::new(&instructions);
+        let result = Progress::new()
+            .with_timeout(Some(Duration::ZERO))
+            .run(|| processor.run());
+        assert_eq!(result, Err("timed out after 0ms".into()));
+    }
+
     #[test]
     fn test_jump_backwards() {
         let program = "inc a
@@ -338,9 +420,9 @@ mod tests {
     fn test_conditional_jumps() {
         let program = "inc a
                              jio a, +2
-                             inc a,
-                             inc a,
-                             inc a,
+                             inc a
+                             inc a
+                             inc a
                              jie a, +100
                              jio a, +100
                              inc a
@@ -354,7 +436,7 @@ mod tests {
             .collect();
 
         let mut processor = Processor::new(&instructions);
-        processor.run();
+        processor.run().unwrap();
         assert_eq!(processor.get_register(Register::A), 4);
         assert_eq!(processor.get_register(Register::B), 2);
     }
@@ -1,19 +1,14 @@
 //! Advent of code 2015 day 23 part 1
 
-use std::io::BufRead;
-
 use day_23_1::{Instruction, Processor, Register};
 
-fn main() {
-    let file = std::fs::File::open("input/input.txt").unwrap();
-    let reader = std::io::BufReader::new(file);
-    let instructions: Vec<_> = reader
-        .lines()
-        .map(|line| { Instruction::try_from(line.unwrap().as_ref()) }.unwrap())
-        .collect();
+fn main() -> common::Result<()> {
+    let input = common::input::load(env!("CARGO_MANIFEST_DIR"), 2015, 23)?;
+    let instructions: Vec<Instruction> = common::parse_lines(&input)?;
 
     let mut processor = Processor::new(&instructions);
-    processor.run();
+    processor.run()?;
 
     println!("Register B: {}", processor.get_register(Register::B));
+    Ok(())
 }
@@ -0,0 +1,114 @@
+use common::generate::Rng;
+use common::solution::{Result, Solution};
+use day_23_1::{Instruction, Processor, Register, RegisterValue};
+
+fn run(instructions: &[Instruction], a: RegisterValue) -> Result<RegisterValue> {
+    let mut processor = Processor::new(instructions);
+    processor.set_register(Register::A, a);
+    processor.run()?;
+    Ok(processor.get_register(Register::B))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 23;
+    const INPUT_SIZE: usize = 20;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = RegisterValue;
+    type Answer2 = RegisterValue;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>> {
+        common::parse_lines(input)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Result<RegisterValue> {
+        run(instructions, 0)
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Result<RegisterValue> {
+        run(instructions, 1)
+    }
+
+    /// Generates a program shaped like the real one: two blocks of `size` instructions compute
+    /// a start value in register a, one for each part, and a loop then counts the steps it takes
+    /// that value to reach 1 in the Collatz sequence.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // keeps all values of the Collatz sequences well within a register
+        const LIMIT: RegisterValue = 100_000;
+
+        let mut block = |start: RegisterValue| {
+            let mut value = start;
+            let mut block = Vec::new();
+            for _ in 0..size {
+                let instruction = match rng.range(0..=5) {
+                    0..=2 if value * 3 < LIMIT => "tpl a",
+                    0..=4 if value + 1 < LIMIT => "inc a",
+                    _ => "hlf a",
+                };
+                value = match instruction {
+                    "tpl a" => value * 3,
+                    "inc a" => value + 1,
+                    _ => value / 2,
+                };
+                block.push(instruction);
+            }
+            // the sequence of 0 never reaches 1
+            if value == 0 {
+                block.push("inc a");
+            }
+            block
+        };
+        let (first, second) = (block(0), block(1));
+
+        let mut program = vec![format!("jio a, +{}", first.len() + 2)];
+        program.extend(first.iter().map(ToString::to_string));
+        program.push(format!("jmp +{}", second.len() + 1));
+        program.extend(second.iter().map(ToString::to_string));
+        program.extend(
+            [
+                "jio a, +8",
+                "inc b",
+                "jie a, +4",
+                "tpl a",
+                "inc a",
+                "jmp +2",
+                "hlf a",
+                "jmp -7",
+            ]
+            .map(ToString::to_string),
+        );
+        Some(program.iter().map(|line| format!("{line}\n")).collect())
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day23() {
+        let instructions = Day23::parse("inc b\njio a, +2\ntpl b\ninc b\n").unwrap();
+        assert_eq!(Day23::part1(&instructions).unwrap(), 4);
+        assert_eq!(Day23::part2(&instructions).unwrap(), 2);
+        assert!(Day23::parse("nop a\n").is_err());
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = Day23::generate(&mut Rng::new(seed), 30).unwrap();
+            let instructions = Day23::parse(&input).unwrap();
+            // both parts have to reach the end of the program
+            assert!(Day23::part1(&instructions).unwrap() > 0);
+            assert!(Day23::part2(&instructions).unwrap() > 0);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::BTreeSet;
+
+use common::generate::Rng;
+use common::solution::{Answer, Result, Solution, Work};
+use common::{BitSet, LineParser};
+use day_24_1::{apply_mask, best_configuration_for_sum, quantum_entanglement_mask};
+
+/// The quantum entanglement of the first group, showing the packages in it.
+fn first_group(numbers: &[u32], best: &BitSet) -> Answer<u64> {
+    Answer::new(quantum_entanglement_mask(numbers, best))
+        .with_work(Work::items("first group", apply_mask(numbers, best)))
+}
+
+fn best_configuration(values: &[u32]) -> Option<BitSet> {
+    let target = values.iter().sum::<u32>() / 4;
+
+    // This is using a simplification for part 2 where technically this could still produce a
+    // wrong answer.
+    // The function we call here does not check that indeed 3 remaining partitions can be formed
+    // after using some parts for the first group.
+    // However, in practice this works for the given data.
+    best_configuration_for_sum(values, target)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 24;
+    const INPUT_SIZE: usize = 29;
+
+    type Input = Vec<u32>;
+    type Answer1 = Answer<u64>;
+    type Answer2 = Answer<u64>;
+
+    fn parse(input: &str) -> Result<Vec<u32>> {
+        let mut numbers: Vec<u32> = LineParser::new().parse_with(input, str::parse)?;
+
+        // sort in ascending order so that we can select the largest numbers first
+        numbers.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Vec<u32>) -> Result<Answer<u64>> {
+        let best = day_24_1::best_configuration(numbers).ok_or("no valid configuration")?;
+        Ok(first_group(numbers, &best))
+    }
+
+    fn part2(numbers: &Vec<u32>) -> Result<Answer<u64>> {
+        let best = best_configuration(numbers).ok_or("no valid configuration")?;
+        Ok(first_group(numbers, &best))
+    }
+
+    /// Generates `size` distinct package weights (at least 24) that can be split into twelve
+    /// groups of the same weight, so that there are both three and four equal groups.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const GROUPS: usize = 12;
+
+        let size = size.max(2 * GROUPS);
+        let largest = size.div_ceil(GROUPS);
+        let group_weight = rng.range(30 * largest..=60 * largest);
+        let mut weights = BTreeSet::new();
+        for group in 0..GROUPS {
+            let packages = size / GROUPS + usize::from(group < size % GROUPS);
+            // cuts the weight of the group into the packages, until none of them is taken yet
+            loop {
+                let mut cuts = BTreeSet::new();
+                while cuts.len() < packages - 1 {
+                    cuts.insert(rng.range(1..=group_weight - 1));
+                }
+                let bounds: Vec<_> = [0].into_iter().chain(cuts).chain([group_weight]).collect();
+                let packages: BTreeSet<_> = bounds.windows(2).map(|w| w[1] - w[0]).collect();
+                if packages.len() == bounds.len() - 1 && packages.is_disjoint(&weights) {
+                    weights.extend(packages);
+                    break;
+                }
+            }
+        }
+        Some(weights.iter().map(|weight| format!("{weight}\n")).collect())
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::differential;
+    use day_24_1::sum_mask;
+
+    #[test]
+    fn test_best_configuration() {
+        let values = vec![11, 10, 9, 8, 7, 5, 4, 3, 2, 1];
+        let best = best_configuration(&values).unwrap();
+        assert_eq!(apply_mask(&values, &best), vec![11, 4]);
+        assert_eq!(sum_mask(&values, &best), 15);
+        assert_eq!(quantum_entanglement_mask(&values, &best), 44);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let input = Day24::generate(&mut Rng::new(seed), 26).unwrap();
+            let numbers = Day24::parse(&input).unwrap();
+            assert_eq!(numbers.len(), 26);
+            assert!(Day24::part1(&numbers).is_ok());
+            assert!(Day24::part2(&numbers).is_ok());
+        }
+    }
+
+    /// The lowest quantum entanglement of the smallest groups that leave enough packages for a
+    /// second group, trying every subset of the packages in order of size.
+    fn naive_part1(numbers: &[u32]) -> Option<u64> {
+        fn sums_to_target(
+            values: &[u32],
+            k: usize,
+            target: u32,
+        ) -> impl Iterator<Item = BitSet> + '_ {
+            BitSet::full(values.len())
+                .k_subsets(k)
+                .filter(move |mask| sum_mask(values, mask) == target)
+        }
+
+        let target = numbers.iter().sum::<u32>() / 3;
+        (1..=numbers.len()).find_map(|k| {
+            sums_to_target(numbers, k, target)
+                .filter(|mask| {
+                    // the sums that some of the other packages add up to
+                    let mut reachable = vec![false; target as usize + 1];
+                    reachable[0] = true;
+                    for i in (0..numbers.len()).filter(|&i| !mask.contains(i)) {
+                        for sum in (numbers[i] as usize..=target as usize).rev() {
+                            reachable[sum] |= reachable[sum - numbers[i] as usize];
+                        }
+                    }
+                    reachable[target as usize]
+                })
+                .map(|mask| quantum_entanglement_mask(numbers, &mask))
+                .min()
+        })
+    }
+
+    #[test]
+    fn test_differential() {
+        differential::check::<Day24, _>(
+            [24, 25, 26],
+            8,
+            |numbers| naive_part1(numbers),
+            |numbers| Day24::part1(numbers).ok().map(|answer| answer.value),
+        );
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(super::Day24: ten_packages);
+}
@@ -0,0 +1,166 @@
+use common::generate::Rng;
+use common::progress;
+use common::solution::{Part, Result, Solution};
+
+/// The 1-based position of a cell in the order the codes are generated in, diagonal by diagonal.
+///
+/// The sum of `row` and `column` has to fit in a `u32`.
+#[must_use]
+pub fn row_column_to_index(row: u32, column: u32) -> u64 {
+    // on any given diagonal, the sum of the row and column is constant
+    let diagonal = u64::from(row) + u64::from(column) - 1;
+
+    // the area of the triangle before the diagonal
+    let area_before = diagonal * (diagonal - 1) / 2;
+
+    // the position of the cell on the diagonal
+    let position_on_diagonal = u64::from(column);
+
+    area_before + position_on_diagonal
+}
+
+/// The number of codes generated between two progress reports.
+const CODES_PER_REPORT: u64 = 1 << 20;
+
+/// The code in the cell at `row` and `column`, both starting at 1.
+///
+/// # Errors
+///
+/// Returns an `Err` if the sum of `row` and `column` does not fit in a `u32`, or if the search was
+/// cancelled or ran out of time, which far away cells can take long enough for.
+pub fn code(row: u32, column: u32) -> Result<u64> {
+    if row.checked_add(column).is_none() {
+        return Err(format!("row {row}, column {column} is too far away").into());
+    }
+    // we could do modular exponentiation here, but the number of iterations is small enough that
+    // we can just loop
+    let reporter = progress::current();
+    let index = row_column_to_index(row, column);
+    let mut value = 20_151_125;
+    for i in 1..index {
+        value = (value * 252_533) % 33_554_393;
+        if i.is_multiple_of(CODES_PER_REPORT) {
+            reporter.report("codes", i, Some(index))?;
+        }
+    }
+
+    Ok(value)
+}
+
+/// The code at the position of the puzzle input. There is no second part on the last day.
+///
+/// # Errors
+///
+/// Returns an `Err` if the input lacks the row or the column.
+pub fn part1(input: &str) -> Result<u64> {
+    Day25::part1(&Day25::parse(input)?)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 25;
+    const PARTS: &'static [Part] = &[Part::One];
+    const INPUT_SIZE: usize = 3000;
+
+    type Input = (u32, u32);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<(u32, u32)> {
+        let number_after = |key| -> Result<u32> {
+            let (_, rest) = input
+                .split_once(key)
+                .ok_or_else(|| format!("missing {key}"))?;
+            let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+            Ok(digits.parse()?)
+        };
+        let (row, column) = (number_after("row ")?, number_after("column ")?);
+        if row == 0 || column == 0 {
+            return Err("rows and columns start at 1".into());
+        }
+        Ok((row, column))
+    }
+
+    fn part1(&(row, column): &(u32, u32)) -> Result<u64> {
+        code(row, column)
+    }
+
+    fn part2(_: &(u32, u32)) -> Result<u64> {
+        Err("day 25 has no second part".into())
+    }
+
+    /// Generates a position in the first `size` rows and columns.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = u32::try_from(size).unwrap_or(u32::MAX).max(1);
+        let (row, column) = (rng.range(1..=size), rng.range(1..=size));
+        Some(format!(
+            "To continue, please consult the code grid in the manual.  \
+             Enter the code at row {row}, column {column}.\n"
+        ))
+    }
+
+    #[cfg(feature = "serde")]
+    fn input_json(input: &Self::Input) -> Result<String> {
+        common::solution::to_json(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use common::progress::Progress;
+
+    #[test]
+    fn test_row_column_to_index() {
+        assert_eq!(row_column_to_index(1, 1), 1);
+        assert_eq!(row_column_to_index(2, 1), 2);
+        assert_eq!(row_column_to_index(3, 1), 4);
+
+        assert_eq!(row_column_to_index(1, 2), 3);
+        assert_eq!(row_column_to_index(2, 2), 5);
+        assert_eq!(row_column_to_index(3, 2), 8);
+
+        assert_eq!(row_column_to_index(5, 2), 17);
+        assert_eq!(row_column_to_index(2, 5), 20);
+    }
+
+    #[test]
+    fn test_code() {
+        assert_eq!(code(1, 1).unwrap(), 20151125);
+        assert_eq!(code(2, 1).unwrap(), 31916031);
+        assert_eq!(code(3, 1).unwrap(), 16080970);
+
+        assert_eq!(code(1, 2).unwrap(), 18749137);
+        assert_eq!(code(2, 2).unwrap(), 21629792);
+        assert_eq!(code(3, 2).unwrap(), 8057251);
+
+        assert_eq!(code(5, 2).unwrap(), 17552253);
+        assert_eq!(code(2, 5).unwrap(), 15514188);
+    }
+
+    #[test]
+    fn test_code_timeout() {
+        let result = Progress::new()
+            .with_timeout(Some(Duration::ZERO))
+            .run(|| code(1 << 30, 1 << 30));
+        assert_eq!(result, Err("timed out after 0ms".into()));
+        assert!(code(u32::MAX, 1).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = "Enter the code at row 2978, column 3083.";
+        assert_eq!(Day25::parse(input).unwrap(), (2978, 3083));
+        assert!(Day25::parse("Enter the code at row 2978.").is_err());
+        assert!(Day25::parse("Enter the code at row 0, column 1.").is_err());
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    common::example_tests!(super::Day25: first_code, second_row, sixth_row_sixth_column);
+}
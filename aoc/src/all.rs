@@ -0,0 +1,561 @@
+use std::any::Any;
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use common::input;
+use common::memory::{self, format_bytes, Usage};
+use common::solution::{DynSolution, Error, Result};
+use rayon::prelude::*;
+use serde_json::json;
+
+use crate::answers::{Answers, Verification};
+use crate::bench::nanos;
+use crate::verify::{Outcome, Status, Summary};
+
+/// The README of the repository, relative to the runner crate.
+pub const README: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../README.md");
+
+/// The lines of the README between which the status table is kept.
+const README_BEGIN: &str = "<!-- status:begin -->";
+const README_END: &str = "<!-- status:end -->";
+
+/// How all solutions are run and where the summary goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// The number of threads, or 0 for one per core.
+    pub jobs: usize,
+    /// Where to write the summary as Markdown.
+    pub markdown: Option<PathBuf>,
+    /// Where to write the summary as JSON.
+    pub json: Option<PathBuf>,
+    /// Whether to replace the status table of the README.
+    pub readme: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            jobs: 1,
+            markdown: None,
+            json: None,
+            readme: false,
+        }
+    }
+}
+
+impl Options {
+    /// Removes the options of `all` from the arguments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if an option is given twice or has an invalid value.
+    pub fn take(args: &mut Vec<String>) -> Result<Self> {
+        let jobs = input::take_option(args, "--jobs")?
+            .map(|jobs| {
+                jobs.parse()
+                    .map_err(|_| Error::from(format!("invalid value for --jobs: {jobs}")))
+            })
+            .transpose()?;
+        Ok(Self {
+            jobs: jobs.unwrap_or(Self::default().jobs),
+            markdown: input::take_option(args, "--markdown")?.map(PathBuf::from),
+            json: input::take_option(args, "--json")?.map(PathBuf::from),
+            readme: input::take_flag(args, "--readme")?,
+        })
+    }
+}
+
+/// A verified part together with how long solving it took.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Row {
+    pub outcome: Outcome,
+    /// `None` if the input could not be loaded.
+    pub runtime: Option<Duration>,
+    /// `None` if the input could not be loaded or memory was not measured.
+    pub memory: Option<Usage>,
+}
+
+/// Solves and verifies all parts of a solution, one after the other.
+fn run_solution(
+    solution: &dyn DynSolution,
+    answers: &Answers,
+    load: impl Fn(&dyn DynSolution) -> Result<Box<dyn Any>>,
+    measure_memory: bool,
+) -> Vec<Row> {
+    let (year, day) = (solution.year(), solution.day());
+    let input = load(solution);
+    solution
+        .parts()
+        .iter()
+        .map(|&part| {
+            let (status, runtime, memory) = match &input {
+                Ok(input) => {
+                    let ((answer, runtime), usage) = memory::measure(|| {
+                        let start = Instant::now();
+                        let answer = solution.solve(input.as_ref(), part);
+                        (answer, start.elapsed())
+                    });
+                    (
+                        Status::new(answers, (year, day, part), answer),
+                        Some(runtime),
+                        measure_memory.then_some(usage),
+                    )
+                }
+                Err(e) => (Status::Error(e.to_string()), None, None),
+            };
+            Row {
+                outcome: Outcome {
+                    year,
+                    day,
+                    part,
+                    status,
+                },
+                runtime,
+                memory,
+            }
+        })
+        .collect()
+}
+
+/// Runs all parts of the given solutions on `jobs` threads and checks their answers against the
+/// manifest, returning the rows in the order of the solutions.
+///
+/// The days are spread over the threads, while the parts of a day run on the same one. With 0
+/// jobs there is one thread per core. With 1 job the days run one after another on the calling
+/// thread, so that solutions with parallel iterators still get the global pool. Note that the
+/// runtimes of parallel runs include the contention between the threads.
+///
+/// With `measure_memory` the rows also have the memory usage of the parts, which needs a
+/// `common::memory::CountingAllocator` as the global allocator.
+///
+/// # Errors
+///
+/// Returns an `Err` if the threads cannot be started, or memory is to be measured on more than one
+/// thread, where the parts would count each other's allocations.
+pub fn run_all(
+    solutions: &[&dyn DynSolution],
+    answers: &Answers,
+    load: impl Fn(&dyn DynSolution) -> Result<Box<dyn Any>> + Sync,
+    jobs: usize,
+    measure_memory: bool,
+) -> Result<Vec<Row>> {
+    if measure_memory && jobs != 1 {
+        return Err("memory can only be measured with --jobs 1".into());
+    }
+    let run = |&solution: &&dyn DynSolution| run_solution(solution, answers, &load, measure_memory);
+    if jobs == 1 {
+        return Ok(solutions.iter().flat_map(run).collect());
+    }
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .map_err(|e| format!("cannot start {jobs} threads: {e}"))?;
+    let rows: Vec<Vec<Row>> = pool.install(|| solutions.par_iter().map(run).collect());
+    Ok(rows.into_iter().flatten().collect())
+}
+
+/// Counts the rows by their status.
+#[must_use]
+pub fn summarize(rows: &[Row]) -> Summary {
+    let mut summary = Summary::default();
+    for row in rows {
+        summary.add(&row.outcome);
+    }
+    summary
+}
+
+/// Whether the memory usage of the rows was measured, which adds two columns to the tables.
+fn has_memory(rows: &[Row]) -> bool {
+    rows.iter().any(|row| row.memory.is_some())
+}
+
+fn header(memory: bool) -> Vec<&'static str> {
+    let mut header = vec!["Year", "Day", "Part", "Answer", "Status", "Time"];
+    if memory {
+        header.extend(["Peak", "Allocated"]);
+    }
+    header
+}
+
+/// Whether a column holds text, which is left-aligned unlike the numbers.
+fn is_text(column: usize) -> bool {
+    matches!(column, 3 | 4)
+}
+
+/// The cells of a row in the order of `header`.
+fn cells(row: &Row, memory: bool) -> Vec<String> {
+    let Outcome {
+        year,
+        day,
+        part,
+        ref status,
+    } = row.outcome;
+    let (answer, status) = match status {
+        Status::Solved {
+            answer,
+            verification,
+        } => (answer.clone(), verification.to_string()),
+        Status::Error(e) => (String::new(), format!("ERROR ({e})")),
+    };
+    let runtime = row
+        .runtime
+        .map_or_else(|| "-".to_string(), |runtime| format!("{runtime:.2?}"));
+    let mut cells = vec![
+        year.to_string(),
+        day.to_string(),
+        part.to_string(),
+        answer,
+        status,
+        runtime,
+    ];
+    if memory {
+        let bytes = |count: fn(&Usage) -> usize| {
+            row.memory
+                .map_or_else(|| "-".to_string(), |usage| format_bytes(count(&usage)))
+        };
+        cells.extend([bytes(|usage| usage.peak), bytes(|usage| usage.total)]);
+    }
+    cells
+}
+
+/// Formats the rows as a plain text table with aligned columns.
+#[must_use]
+pub fn table(rows: &[Row]) -> String {
+    let memory = has_memory(rows);
+    let header: Vec<_> = header(memory).into_iter().map(str::to_string).collect();
+    let body: Vec<_> = rows.iter().map(|row| cells(row, memory)).collect();
+    let mut widths: Vec<_> = header.iter().map(String::len).collect();
+    for cells in &body {
+        for (width, cell) in widths.iter_mut().zip(cells) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for cells in std::iter::once(&header).chain(&body) {
+        let line = cells
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(column, (cell, &width))| {
+                if is_text(column) {
+                    format!("{cell:<width$}")
+                } else {
+                    format!("{cell:>width$}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(table, "{}", line.trim_end()).expect("writing to a string cannot fail");
+    }
+    table
+}
+
+/// Formats the rows as a Markdown table, linking every day to its puzzle.
+#[must_use]
+pub fn markdown(rows: &[Row]) -> String {
+    let memory = has_memory(rows);
+    let header = header(memory);
+    let mut markdown = format!("| {} |\n|", header.join(" | "));
+    for column in 0..header.len() {
+        markdown.push_str(if is_text(column) { " --- |" } else { " ---: |" });
+    }
+    markdown.push('\n');
+    for row in rows {
+        // a pipe would end the cell
+        let mut cells: Vec<_> = cells(row, memory)
+            .into_iter()
+            .map(|cell| cell.replace('|', "\\|"))
+            .collect();
+        cells[1] = format!(
+            "[{day}](https://adventofcode.com/{year}/day/{day})",
+            year = cells[0],
+            day = cells[1]
+        );
+        if !cells[3].is_empty() {
+            cells[3] = format!("`{}`", cells[3]);
+        }
+        writeln!(markdown, "| {} |", cells.join(" | ")).expect("writing to a string cannot fail");
+    }
+    markdown
+}
+
+/// Formats the rows and their summary as JSON.
+#[must_use]
+pub fn to_json(rows: &[Row]) -> String {
+    let parts: Vec<_> = rows
+        .iter()
+        .map(|row| {
+            let outcome = &row.outcome;
+            let mut part = json!({
+                "year": outcome.year,
+                "day": outcome.day,
+                "part": outcome.part.to_string(),
+                "runtime_ns": row.runtime.map(nanos),
+            });
+            if let Some(usage) = row.memory {
+                part["peak_bytes"] = json!(usage.peak);
+                part["allocated_bytes"] = json!(usage.total);
+            }
+            match &outcome.status {
+                Status::Solved {
+                    answer,
+                    verification,
+                } => {
+                    part["answer"] = json!(answer);
+                    part["status"] = json!(match verification {
+                        Verification::Pass => "pass",
+                        Verification::Fail { .. } => "fail",
+                        Verification::Missing => "missing",
+                    });
+                    if let Verification::Fail { expected } = verification {
+                        part["expected"] = json!(expected);
+                    }
+                }
+                Status::Error(e) => {
+                    part["status"] = json!("error");
+                    part["error"] = json!(e);
+                }
+            }
+            part
+        })
+        .collect();
+    let summary = summarize(rows);
+    let mut json = serde_json::to_string_pretty(&json!({
+        "parts": parts,
+        "summary": {
+            "passed": summary.passed,
+            "failed": summary.failed,
+            "missing": summary.missing,
+            "errors": summary.errors,
+        },
+    }))
+    .expect("the rows can always be serialized");
+    json.push('\n');
+    json
+}
+
+/// Replaces the status table of the README, which is kept between two marker lines.
+///
+/// # Errors
+///
+/// Returns an `Err` if the README lacks the markers.
+pub fn update_readme(readme: &str, markdown: &str) -> Result<String> {
+    let missing = || format!("the README has no {README_BEGIN} and {README_END} lines");
+    let (before, rest) = readme.split_once(README_BEGIN).ok_or_else(missing)?;
+    let (_, after) = rest.split_once(README_END).ok_or_else(missing)?;
+    Ok(format!(
+        "{before}{README_BEGIN}\n{markdown}{README_END}{after}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::solution::{Part, Registered, Solution};
+
+    struct Double;
+
+    impl Solution for Double {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+
+        type Input = u32;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(input: &str) -> Result<u32> {
+            Ok(input.trim().parse()?)
+        }
+
+        fn part1(input: &u32) -> Result<u32> {
+            Ok(input * 2)
+        }
+
+        fn part2(_: &u32) -> Result<u32> {
+            Err("no | way".into())
+        }
+    }
+
+    fn row(part: Part, status: Status, runtime: Option<Duration>) -> Row {
+        Row {
+            outcome: Outcome {
+                year: 2015,
+                day: 1,
+                part,
+                status,
+            },
+            runtime,
+            memory: None,
+        }
+    }
+
+    fn rows() -> Vec<Row> {
+        vec![
+            row(
+                Part::One,
+                Status::Solved {
+                    answer: "42".to_string(),
+                    verification: Verification::Fail {
+                        expected: "43".to_string(),
+                    },
+                },
+                Some(Duration::from_micros(1500)),
+            ),
+            row(Part::Two, Status::Error("no | way".to_string()), None),
+        ]
+    }
+
+    #[test]
+    fn test_run_all() {
+        let solution = Registered::<Double>::new();
+        let answers = Answers::parse("2015 1 1 42").unwrap();
+        for jobs in [1, 4] {
+            let rows = run_all(
+                &[&solution, &solution, &solution],
+                &answers,
+                |s| s.parse("21"),
+                jobs,
+                false,
+            )
+            .unwrap();
+            assert_eq!(rows.len(), 6);
+            assert!(rows.iter().all(|row| row.runtime.is_some()));
+            assert_eq!(
+                summarize(&rows),
+                Summary {
+                    passed: 3,
+                    errors: 3,
+                    ..Summary::default()
+                }
+            );
+        }
+
+        let rows = run_all(&[&solution], &answers, |_| Err("no input".into()), 1, false).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert!(rows.iter().all(|row| row.runtime.is_none()));
+
+        let rows = run_all(&[&solution], &answers, |s| s.parse("21"), 1, true).unwrap();
+        assert!(rows.iter().all(|row| row.memory.is_some()));
+        assert!(run_all(&[&solution], &answers, |s| s.parse("21"), 2, true).is_err());
+    }
+
+    struct Pool;
+
+    impl Solution for Pool {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 2;
+        const PARTS: &'static [Part] = &[Part::One];
+
+        type Input = ();
+        type Answer1 = String;
+        type Answer2 = String;
+
+        fn parse(_: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn part1((): &()) -> Result<String> {
+            Ok(format!("{:?}", rayon::current_thread_index()))
+        }
+
+        fn part2((): &()) -> Result<String> {
+            Err("no second part".into())
+        }
+    }
+
+    fn pool_thread(jobs: usize) -> String {
+        let solution = Registered::<Pool>::new();
+        let rows = run_all(
+            &[&solution],
+            &Answers::default(),
+            |s| s.parse(""),
+            jobs,
+            false,
+        );
+        match &rows.unwrap()[0].outcome.status {
+            Status::Solved { answer, .. } => answer.clone(),
+            Status::Error(e) => panic!("{e}"),
+        }
+    }
+
+    #[test]
+    fn test_run_all_pool() {
+        // a single job stays on the calling thread, outside of any pool
+        assert_eq!(pool_thread(1), "None");
+        assert_ne!(pool_thread(2), "None");
+    }
+
+    #[test]
+    fn test_table() {
+        assert_eq!(
+            table(&rows()),
+            "Year  Day  Part  Answer  Status               Time\n\
+             2015    1     1  42      FAIL, expected 43  1.50ms\n\
+             2015    1     2          ERROR (no | way)        -\n"
+        );
+    }
+
+    #[test]
+    fn test_table_memory() {
+        let mut rows = rows();
+        rows[0].memory = Some(Usage {
+            peak: 2048,
+            total: 3000,
+        });
+        assert_eq!(
+            table(&rows),
+            "Year  Day  Part  Answer  Status               Time     Peak  Allocated\n\
+             2015    1     1  42      FAIL, expected 43  1.50ms  2.0 KiB    2.9 KiB\n\
+             2015    1     2          ERROR (no | way)        -        -          -\n"
+        );
+        assert!(markdown(&rows).starts_with(
+            "| Year | Day | Part | Answer | Status | Time | Peak | Allocated |\n\
+             | ---: | ---: | ---: | --- | --- | ---: | ---: | ---: |\n"
+        ));
+    }
+
+    #[test]
+    fn test_markdown() {
+        assert_eq!(
+            markdown(&rows()),
+            "| Year | Day | Part | Answer | Status | Time |\n\
+             | ---: | ---: | ---: | --- | --- | ---: |\n\
+             | 2015 | [1](https://adventofcode.com/2015/day/1) | 1 | `42` | FAIL, expected 43 \
+             | 1.50ms |\n\
+             | 2015 | [1](https://adventofcode.com/2015/day/1) | 2 |  | ERROR (no \\| way) | - |\n"
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json: serde_json::Value = serde_json::from_str(&to_json(&rows())).unwrap();
+        assert_eq!(
+            json["parts"][0],
+            json!({
+                "year": 2015,
+                "day": 1,
+                "part": "1",
+                "answer": "42",
+                "status": "fail",
+                "expected": "43",
+                "runtime_ns": 1_500_000,
+            })
+        );
+        assert_eq!(json["parts"][1]["status"], "error");
+        assert_eq!(json["parts"][1]["runtime_ns"], serde_json::Value::Null);
+        assert_eq!(json["summary"]["failed"], 1);
+        assert_eq!(json["summary"]["errors"], 1);
+    }
+
+    #[test]
+    fn test_update_readme() {
+        let readme = "# title\n\n<!-- status:begin -->\nold\n<!-- status:end -->\n\nmore\n";
+        assert_eq!(
+            update_readme(readme, "new\n").unwrap(),
+            "# title\n\n<!-- status:begin -->\nnew\n<!-- status:end -->\n\nmore\n"
+        );
+        assert!(update_readme("# title\n", "new\n").is_err());
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use common::solution::{Part, Result};
+
+/// The manifest of known answers, relative to the runner crate.
+pub const MANIFEST: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt");
+
+/// Identifies a single answer.
+pub type Key = (u16, u8, Part);
+
+/// Known correct answers, one per year, day and part.
+///
+/// The manifest is a text file with one `<year> <day> <part> <answer>` entry per line. Empty lines
+/// and lines starting with `#` are ignored.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<Key, String>,
+}
+
+impl Answers {
+    /// Parses a manifest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if a line is malformed or an answer is given twice.
+    pub fn parse(manifest: &str) -> Result<Self> {
+        let mut answers = BTreeMap::new();
+        for (number, line) in manifest.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("invalid answer on line {}: {line}", number + 1);
+            let fields: Vec<_> = line.split_whitespace().collect();
+            let [year, day, part, answer] = fields[..] else {
+                return Err(invalid().into());
+            };
+            let year = year.parse().map_err(|_| invalid())?;
+            let day = day.parse().map_err(|_| invalid())?;
+            let part = part.parse().map_err(|_| invalid())?;
+
+            if answers
+                .insert((year, day, part), answer.to_string())
+                .is_some()
+            {
+                return Err(format!("duplicate answer on line {}: {line}", number + 1).into());
+            }
+        }
+        Ok(Self { answers })
+    }
+
+    /// Reads the manifest from disk, treating a missing file as empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the file cannot be read or parsed.
+    pub fn load(path: &str) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(manifest) => Self::parse(&manifest),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("cannot read {path}: {e}").into()),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, key: Key) -> Option<&str> {
+        self.answers.get(&key).map(String::as_str)
+    }
+}
+
+/// The outcome of checking a computed answer against the manifest.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verification {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Verification {
+    #[must_use]
+    pub fn check(answers: &Answers, key: Key, actual: &str) -> Self {
+        match answers.get(key) {
+            Some(expected) if expected == actual => Self::Pass,
+            Some(expected) => Self::Fail {
+                expected: expected.to_string(),
+            },
+            None => Self::Missing,
+        }
+    }
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail { expected } => write!(f, "FAIL, expected {expected}"),
+            Self::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("# comment\n\n2015  7 2 14134\n2015 11 1 hxbxxyzz\n").unwrap();
+        assert_eq!(answers.get((2015, 7, Part::Two)), Some("14134"));
+        assert_eq!(answers.get((2015, 11, Part::One)), Some("hxbxxyzz"));
+        assert_eq!(answers.get((2015, 7, Part::One)), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Answers::parse("2015 7 2").is_err());
+        assert!(Answers::parse("2015 7 3 1").is_err());
+        assert!(Answers::parse("x 7 2 1").is_err());
+        assert!(Answers::parse("2015 7 2 1\n2015 7 2 1").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("2015 1 1 74").unwrap();
+        assert_eq!(
+            Verification::check(&answers, (2015, 1, Part::One), "74"),
+            Verification::Pass
+        );
+        assert_eq!(
+            Verification::check(&answers, (2015, 1, Part::One), "75"),
+            Verification::Fail {
+                expected: "74".to_string()
+            }
+        );
+        assert_eq!(
+            Verification::check(&answers, (2015, 1, Part::Two), "1795"),
+            Verification::Missing
+        );
+    }
+
+    #[test]
+    fn test_manifest_is_valid() {
+        let answers = Answers::load(MANIFEST).unwrap();
+        assert_eq!(answers.get((2015, 25, Part::Two)), None);
+        assert!(answers.get((2015, 1, Part::One)).is_some());
+    }
+}
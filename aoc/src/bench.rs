@@ -0,0 +1,490 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use common::input;
+use common::solution::{DynSolution, Error, Part, Result};
+use serde_json::{json, Value};
+
+/// How benchmarks are run and compared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// Untimed runs before the samples are taken.
+    pub warmup: usize,
+    /// Timed runs per phase.
+    pub samples: usize,
+    /// Where to write the results as JSON.
+    pub output: Option<PathBuf>,
+    /// Earlier results to compare against.
+    pub baseline: Option<PathBuf>,
+    /// How much slower than the baseline (in percent) a phase may get before it is flagged.
+    pub threshold: u32,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            samples: 10,
+            output: None,
+            baseline: None,
+            threshold: 10,
+        }
+    }
+}
+
+impl Options {
+    /// Removes the benchmark options from the arguments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if an option is given twice or has an invalid value.
+    pub fn take(args: &mut Vec<String>) -> Result<Self> {
+        fn number<T: std::str::FromStr>(name: &str, value: Option<String>) -> Result<Option<T>> {
+            value
+                .map(|value| {
+                    value
+                        .parse()
+                        .map_err(|_| Error::from(format!("invalid value for {name}: {value}")))
+                })
+                .transpose()
+        }
+
+        let defaults = Self::default();
+        let options = Self {
+            warmup: number("--warmup", input::take_option(args, "--warmup")?)?
+                .unwrap_or(defaults.warmup),
+            samples: number("--samples", input::take_option(args, "--samples")?)?
+                .unwrap_or(defaults.samples),
+            output: input::take_option(args, "--output")?.map(PathBuf::from),
+            baseline: input::take_option(args, "--baseline")?.map(PathBuf::from),
+            threshold: number("--threshold", input::take_option(args, "--threshold")?)?
+                .unwrap_or(defaults.threshold),
+        };
+        if options.samples == 0 {
+            return Err("--samples must be at least 1".into());
+        }
+        Ok(options)
+    }
+}
+
+/// The step of a solution that is timed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Solve(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+impl std::str::FromStr for Phase {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "parse" => Ok(Self::Parse),
+            _ => Ok(Self::Solve(
+                s.strip_prefix("part")
+                    .ok_or_else(|| format!("invalid phase: {s}"))?
+                    .parse()?,
+            )),
+        }
+    }
+}
+
+/// The samples taken for one phase of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub year: u16,
+    pub day: u8,
+    pub phase: Phase,
+    /// Sorted from fastest to slowest, never empty.
+    samples: Vec<Duration>,
+}
+
+impl Timing {
+    /// # Panics
+    ///
+    /// Panics if there are no samples.
+    #[must_use]
+    pub fn new(year: u16, day: u8, phase: Phase, mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "a timing needs at least one sample");
+        samples.sort_unstable();
+        Self {
+            year,
+            day,
+            phase,
+            samples,
+        }
+    }
+
+    #[must_use]
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    #[must_use]
+    pub fn median(&self) -> Duration {
+        let n = self.samples.len();
+        if n % 2 == 1 {
+            self.samples[n / 2]
+        } else {
+            (self.samples[n / 2 - 1] + self.samples[n / 2]) / 2
+        }
+    }
+
+    #[must_use]
+    pub fn mean(&self) -> Duration {
+        self.samples.iter().sum::<Duration>() / u32::try_from(self.samples.len()).unwrap_or(1)
+    }
+
+    fn key(&self) -> (u16, u8, Phase) {
+        (self.year, self.day, self.phase)
+    }
+}
+
+/// Runs `f` `warmup` times untimed and then `samples` times timed.
+///
+/// # Errors
+///
+/// Returns the first `Err` returned by `f`.
+pub fn measure<T>(options: &Options, mut f: impl FnMut() -> Result<T>) -> Result<Vec<Duration>> {
+    for _ in 0..options.warmup {
+        std::hint::black_box(f()?);
+    }
+    (0..options.samples)
+        .map(|_| {
+            let start = Instant::now();
+            let result = std::hint::black_box(f()?);
+            let elapsed = start.elapsed();
+            drop(result);
+            Ok(elapsed)
+        })
+        .collect()
+}
+
+/// Times parsing the input and solving each part of a solution separately.
+///
+/// # Errors
+///
+/// Returns an `Err` if parsing or solving fails.
+pub fn bench(solution: &dyn DynSolution, input: &str, options: &Options) -> Result<Vec<Timing>> {
+    let (year, day) = (solution.year(), solution.day());
+    let mut timings = vec![Timing::new(
+        year,
+        day,
+        Phase::Parse,
+        measure(options, || solution.parse(input))?,
+    )];
+
+    let parsed = solution.parse(input)?;
+    for &part in solution.parts() {
+        let samples = measure(options, || solution.solve(parsed.as_ref(), part))?;
+        timings.push(Timing::new(year, day, Phase::Solve(part), samples));
+    }
+    Ok(timings)
+}
+
+/// Timings as saved to and loaded from a JSON file.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub timings: Vec<Timing>,
+}
+
+/// A duration in whole nanoseconds, as written to JSON.
+pub fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+impl Report {
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let timings: Vec<_> = self
+            .timings
+            .iter()
+            .map(|timing| {
+                json!({
+                    "year": timing.year,
+                    "day": timing.day,
+                    "phase": timing.phase.to_string(),
+                    "min_ns": nanos(timing.min()),
+                    "median_ns": nanos(timing.median()),
+                    "mean_ns": nanos(timing.mean()),
+                    "samples_ns": timing.samples.iter().copied().map(nanos).collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+        let mut json = serde_json::to_string_pretty(&json!({ "timings": timings }))
+            .expect("timings can always be serialized");
+        json.push('\n');
+        json
+    }
+
+    /// Parses a report written by [`Report::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the JSON is malformed or a timing lacks a field.
+    pub fn parse(json: &str) -> Result<Self> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| format!("invalid benchmark report: {e}"))?;
+        let timings = value["timings"]
+            .as_array()
+            .ok_or("invalid benchmark report: missing timings")?;
+        let timings = timings
+            .iter()
+            .enumerate()
+            .map(|(i, timing)| {
+                let invalid = || format!("invalid timing {}: {timing}", i + 1);
+                let number = |field: &str| timing[field].as_u64().ok_or_else(invalid);
+                let samples = timing["samples_ns"]
+                    .as_array()
+                    .filter(|samples| !samples.is_empty())
+                    .ok_or_else(invalid)?
+                    .iter()
+                    .map(|sample| sample.as_u64().map(Duration::from_nanos))
+                    .collect::<Option<_>>()
+                    .ok_or_else(invalid)?;
+                Ok(Timing::new(
+                    u16::try_from(number("year")?).map_err(|_| invalid())?,
+                    u8::try_from(number("day")?).map_err(|_| invalid())?,
+                    timing["phase"]
+                        .as_str()
+                        .ok_or_else(invalid)?
+                        .parse()
+                        .map_err(|_| invalid())?,
+                    samples,
+                ))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { timings })
+    }
+
+    /// # Errors
+    ///
+    /// Returns an `Err` if the file cannot be read or parsed.
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+        Self::parse(&json).map_err(|e| e.with_file(path.display().to_string()))
+    }
+
+    /// # Errors
+    ///
+    /// Returns an `Err` if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_json())
+            .map_err(|e| format!("cannot write {}: {e}", path.display()).into())
+    }
+
+    #[must_use]
+    pub fn get(&self, year: u16, day: u8, phase: Phase) -> Option<&Timing> {
+        self.timings.iter().find(|t| t.key() == (year, day, phase))
+    }
+}
+
+/// How a timing relates to its baseline, by median.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    NoBaseline,
+    /// The relative change in percent, within the threshold.
+    Within(f64),
+    /// The relative change in percent, slower than allowed.
+    Regression(f64),
+}
+
+impl Comparison {
+    #[must_use]
+    pub fn new(timing: &Timing, baseline: Option<&Timing>, threshold: u32) -> Self {
+        let Some(baseline) = baseline else {
+            return Self::NoBaseline;
+        };
+        #[allow(clippy::cast_precision_loss)]
+        let (before, after) = (
+            nanos(baseline.median()) as f64,
+            nanos(timing.median()) as f64,
+        );
+        let change = if before > 0.0 {
+            (after - before) * 100.0 / before
+        } else {
+            0.0
+        };
+        if change > f64::from(threshold) {
+            Self::Regression(change)
+        } else {
+            Self::Within(change)
+        }
+    }
+}
+
+/// A timing together with its comparison, formatted as one line of the benchmark output.
+pub struct Line<'a> {
+    pub timing: &'a Timing,
+    pub comparison: Comparison,
+}
+
+impl Display for Line<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let timing = self.timing;
+        write!(
+            f,
+            "{} day {:2} {:<5}: median {:>10.2?}, min {:>10.2?}, mean {:>10.2?}",
+            timing.year,
+            timing.day,
+            timing.phase,
+            timing.median(),
+            timing.min(),
+            timing.mean()
+        )?;
+        match self.comparison {
+            Comparison::NoBaseline => Ok(()),
+            Comparison::Within(change) => write!(f, " ({change:+.1}%)"),
+            Comparison::Regression(change) => write!(f, " (REGRESSION, {change:+.1}%)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::solution::{Registered, Solution};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    fn timing(phase: Phase, millis: &[u64]) -> Timing {
+        Timing::new(
+            2015,
+            4,
+            phase,
+            millis.iter().copied().map(Duration::from_millis).collect(),
+        )
+    }
+
+    struct Count;
+
+    impl Solution for Count {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 25;
+        const PARTS: &'static [Part] = &[Part::One];
+
+        type Input = usize;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<usize> {
+            Ok(input.len())
+        }
+
+        fn part1(input: &usize) -> Result<usize> {
+            Ok(*input)
+        }
+
+        fn part2(_: &usize) -> Result<usize> {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn test_options() {
+        let mut a = args(&["bench", "2015", "--samples", "3", "--threshold=25"]);
+        let options = Options::take(&mut a).unwrap();
+        assert_eq!(a, args(&["bench", "2015"]));
+        assert_eq!(
+            options,
+            Options {
+                samples: 3,
+                threshold: 25,
+                ..Options::default()
+            }
+        );
+
+        assert!(Options::take(&mut args(&["--samples", "0"])).is_err());
+        assert!(Options::take(&mut args(&["--warmup", "x"])).is_err());
+    }
+
+    #[test]
+    fn test_measure() {
+        let options = Options {
+            warmup: 2,
+            samples: 3,
+            ..Options::default()
+        };
+        let mut calls = 0;
+        let samples = measure(&options, || {
+            calls += 1;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(samples.len(), 3);
+        assert_eq!(calls, 5);
+
+        assert!(measure(&options, || Err::<(), _>("broken".into())).is_err());
+    }
+
+    #[test]
+    fn test_bench() {
+        let solution = Registered::<Count>::new();
+        let timings = bench(&solution, "abc", &Options::default()).unwrap();
+        let phases: Vec<_> = timings.iter().map(|t| t.phase).collect();
+        assert_eq!(phases, [Phase::Parse, Phase::Solve(Part::One)]);
+        assert!(timings.iter().all(|t| t.samples.len() == 10));
+    }
+
+    #[test]
+    fn test_statistics() {
+        let timing = timing(Phase::Parse, &[4, 1, 3, 8]);
+        assert_eq!(timing.min(), Duration::from_millis(1));
+        assert_eq!(timing.median(), Duration::from_micros(3500));
+        assert_eq!(timing.mean(), Duration::from_millis(4));
+    }
+
+    #[test]
+    fn test_report_round_trip() {
+        let report = Report {
+            timings: vec![
+                timing(Phase::Parse, &[1]),
+                timing(Phase::Solve(Part::Two), &[5, 6, 7]),
+            ],
+        };
+        let json = report.to_json();
+        assert!(json.contains("\"phase\": \"part2\""));
+        assert!(json.contains("\"median_ns\": 6000000"));
+        assert_eq!(Report::parse(&json).unwrap(), report);
+        assert!(report.get(2015, 4, Phase::Solve(Part::One)).is_none());
+
+        assert!(Report::parse("{}").is_err());
+        assert!(Report::parse(r#"{"timings": [{"year": 2015}]}"#).is_err());
+    }
+
+    #[test]
+    fn test_comparison() {
+        let baseline = timing(Phase::Parse, &[100]);
+        let compare = |millis, threshold| {
+            Comparison::new(&timing(Phase::Parse, &[millis]), Some(&baseline), threshold)
+        };
+        assert_eq!(compare(105, 10), Comparison::Within(5.0));
+        assert_eq!(compare(50, 10), Comparison::Within(-50.0));
+        assert_eq!(compare(120, 10), Comparison::Regression(20.0));
+        assert_eq!(compare(120, 25), Comparison::Within(20.0));
+        assert_eq!(Comparison::new(&baseline, None, 10), Comparison::NoBaseline);
+
+        let line = Line {
+            timing: &timing(Phase::Solve(Part::One), &[120]),
+            comparison: compare(120, 10),
+        };
+        assert_eq!(
+            line.to_string(),
+            "2015 day  4 part1: median   120.00ms, min   120.00ms, mean   120.00ms \
+             (REGRESSION, +20.0%)"
+        );
+    }
+}
@@ -0,0 +1,850 @@
+//! Runner for all Advent of Code solutions
+//!
+//! Usage:
+//!   - `aoc run <year> [<day> [<part>]] [--input <path>|-] [--explain] [--timeout <seconds>]
+//!     [--dump-input]`
+//!   - `aoc verify [<year> [<day>]]`
+//!   - `aoc all [<year>] [--jobs <n>] [--markdown <path>] [--json <path>] [--readme]`
+//!   - `aoc bench [<year> [<day>]] [--warmup <n>] [--samples <n>] [--output <path>]
+//!     [--baseline <path>] [--threshold <percent>]`
+//!   - `aoc fetch <year> <day>`
+//!   - `aoc submit <year> <day> <part> [<answer>] [--input <path>|-]`
+//!   - `aoc new <year> <day>`
+//!   - `aoc generate <year> <day> [--seed <n>] [--size <n>]`
+
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use common::fetch;
+use common::generate::Rng;
+use common::input::{self, Source};
+use common::memory;
+use common::progress::Progress;
+use common::solution::{self, DynSolution, Error, Part, Result};
+
+mod all;
+mod answers;
+mod bench;
+mod registry;
+mod scaffold;
+mod submit;
+mod verify;
+
+use registry::SOLUTIONS;
+
+/// Counts the allocations of the solutions if the `memory` feature is enabled.
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
+
+/// The flag of `run` that prints how each answer was found.
+const EXPLAIN_ARG: &str = "--explain";
+
+/// The option of `run` that stops parts taking longer than the given number of seconds, as far as
+/// they report their progress.
+const TIMEOUT_ARG: &str = "--timeout";
+
+/// The flag of `run` that prints the parsed input of a day as JSON instead of solving it.
+const DUMP_INPUT_ARG: &str = "--dump-input";
+
+/// The options of `generate`.
+const SEED_ARG: &str = "--seed";
+const SIZE_ARG: &str = "--size";
+
+const USAGE: &str = "usage: aoc run <year> [<day> [<part>]] [--input <path>|-] [--explain]
+                 [--timeout <seconds>] [--dump-input]
+       aoc verify [<year> [<day>]]
+       aoc all [<year>] [--jobs <n>] [--markdown <path>] [--json <path>] [--readme]
+       aoc bench [<year> [<day>]] [--warmup <n>] [--samples <n>] [--output <path>]
+                 [--baseline <path>] [--threshold <percent>]
+       aoc fetch <year> <day>
+       aoc submit <year> <day> <part> [<answer>] [--input <path>|-]
+       aoc new <year> <day>
+       aoc generate <year> <day> [--seed <n>] [--size <n>]
+
+--timeout is checked where a solution reports its progress, so it only stops the searches
+of days that do (2015 days 4, 20, 22, 23 and 25).";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Run {
+        year: u16,
+        day: Option<u8>,
+        part: Option<Part>,
+        input: Option<Source>,
+        explain: bool,
+        timeout: Option<u64>,
+        dump_input: bool,
+    },
+    Verify {
+        year: Option<u16>,
+        day: Option<u8>,
+    },
+    All {
+        year: Option<u16>,
+        options: all::Options,
+    },
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+        options: bench::Options,
+    },
+    Fetch {
+        year: u16,
+        day: u8,
+    },
+    Submit {
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: Option<String>,
+        input: Option<Source>,
+    },
+    New {
+        year: u16,
+        day: u8,
+    },
+    Generate {
+        year: u16,
+        day: u8,
+        seed: u64,
+        size: Option<usize>,
+    },
+}
+
+fn parse_year(year: &str) -> Result<u16> {
+    year.parse()
+        .map_err(|_| format!("invalid year: {year}").into())
+}
+
+fn parse_day(day: &str) -> Result<u8> {
+    day.parse()
+        .map_err(|_| format!("invalid day: {day}").into())
+}
+
+/// Removes a numeric option from the arguments.
+fn take_number<T: std::str::FromStr>(args: &mut Vec<String>, name: &str) -> Result<Option<T>> {
+    input::take_option(args, name)?
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| format!("invalid value for {name}: {value}").into())
+        })
+        .transpose()
+}
+
+fn parse_args(mut args: Vec<String>) -> Result<Command> {
+    let input = input::take_input_arg(&mut args)?;
+    let is_run = args.first().is_some_and(|command| command == "run");
+    let explain = is_run && input::take_flag(&mut args, EXPLAIN_ARG)?;
+    let dump_input = is_run && input::take_flag(&mut args, DUMP_INPUT_ARG)?;
+    let timeout = if is_run {
+        take_number(&mut args, TIMEOUT_ARG)?
+    } else {
+        None
+    };
+    let (seed, size) = if args.first().is_some_and(|command| command == "generate") {
+        (
+            take_number(&mut args, SEED_ARG)?,
+            take_number(&mut args, SIZE_ARG)?,
+        )
+    } else {
+        (None, None)
+    };
+    let bench_options = if args.first().is_some_and(|command| command == "bench") {
+        Some(bench::Options::take(&mut args)?)
+    } else {
+        None
+    };
+    let all_options = if args.first().is_some_and(|command| command == "all") {
+        Some(all::Options::take(&mut args)?)
+    } else {
+        None
+    };
+    let command = match args.as_slice() {
+        [command, year, rest @ ..] if command == "run" && rest.len() <= 2 => {
+            let day = rest.first().map(|day| parse_day(day)).transpose()?;
+            if day.is_none() && input.is_some() {
+                return Err(format!("{} requires a day", input::INPUT_ARG).into());
+            }
+            if dump_input && (day.is_none() || rest.len() > 1) {
+                return Err(format!("{DUMP_INPUT_ARG} requires a day and no part").into());
+            }
+            Command::Run {
+                year: parse_year(year)?,
+                day,
+                part: rest.get(1).map(|part| part.parse()).transpose()?,
+                input,
+                explain,
+                timeout,
+                dump_input,
+            }
+        }
+        [command, rest @ ..] if command == "verify" && rest.len() <= 2 && input.is_none() => {
+            Command::Verify {
+                year: rest.first().map(|year| parse_year(year)).transpose()?,
+                day: rest.get(1).map(|day| parse_day(day)).transpose()?,
+            }
+        }
+        [command, year, day] if command == "fetch" && input.is_none() => Command::Fetch {
+            year: parse_year(year)?,
+            day: parse_day(day)?,
+        },
+        [command, year, day] if command == "new" && input.is_none() => Command::New {
+            year: parse_year(year)?,
+            day: parse_day(day)?,
+        },
+        [command, year, day] if command == "generate" && input.is_none() => Command::Generate {
+            year: parse_year(year)?,
+            day: parse_day(day)?,
+            seed: seed.unwrap_or(0),
+            size,
+        },
+        [command, year, day, part, answer @ ..] if command == "submit" && answer.len() <= 1 => {
+            if !answer.is_empty() && input.is_some() {
+                return Err(format!("{} cannot be given with an answer", input::INPUT_ARG).into());
+            }
+            Command::Submit {
+                year: parse_year(year)?,
+                day: parse_day(day)?,
+                part: part.parse()?,
+                answer: answer.first().cloned(),
+                input,
+            }
+        }
+        [_, rest @ ..] if rest.len() <= 2 && input.is_none() => {
+            match (bench_options, all_options) {
+                (Some(options), _) => Command::Bench {
+                    year: rest.first().map(|year| parse_year(year)).transpose()?,
+                    day: rest.get(1).map(|day| parse_day(day)).transpose()?,
+                    options,
+                },
+                (_, Some(options)) if rest.len() <= 1 => Command::All {
+                    year: rest.first().map(|year| parse_year(year)).transpose()?,
+                    options,
+                },
+                _ => return Err(USAGE.into()),
+            }
+        }
+        _ => return Err(USAGE.into()),
+    };
+    Ok(command)
+}
+
+/// Selects the registered solutions of a year (or all years) and optionally a single day.
+fn select(year: Option<u16>, day: Option<u8>) -> Result<Vec<&'static dyn DynSolution>> {
+    if let (Some(year), Some(day)) = (year, day) {
+        return solution::find(SOLUTIONS, year, day)
+            .map(|solution| vec![solution])
+            .ok_or_else(|| format!("no solution for {year} day {day}").into());
+    }
+
+    let solutions: Vec<_> = SOLUTIONS
+        .iter()
+        .copied()
+        .filter(|s| year.is_none_or(|year| s.year() == year))
+        .collect();
+    if solutions.is_empty() {
+        return Err("no matching solutions".into());
+    }
+    Ok(solutions)
+}
+
+/// Reads the raw input of a solution, returning where it was read from.
+fn read_input(solution: &dyn DynSolution, source: Option<&Source>) -> Result<(Source, String)> {
+    let source = match source {
+        Some(source) => source.clone(),
+        None => input::locate(env!("CARGO_MANIFEST_DIR"), solution.year(), solution.day())?,
+    };
+    let input = source.read()?;
+    Ok((source, input))
+}
+
+/// Points an error at the input it was caused by.
+fn in_source(error: Error, source: &Source) -> Error {
+    match source {
+        Source::File(path) => error.with_file(path.display().to_string()),
+        Source::Stdin => error.with_file("<stdin>"),
+    }
+}
+
+/// Reads and parses the input of a solution.
+fn load_input(
+    solution: &dyn DynSolution,
+    source: Option<&Source>,
+) -> Result<Box<dyn std::any::Any>> {
+    let (source, input) = read_input(solution, source)?;
+    solution.parse(&input).map_err(|e| in_source(e, &source))
+}
+
+/// Benchmarks the given solutions, returning whether none regressed against the baseline.
+fn run_bench(solutions: &[&dyn DynSolution], options: &bench::Options) -> Result<bool> {
+    let baseline = options
+        .baseline
+        .as_deref()
+        .map(bench::Report::load)
+        .transpose()?;
+
+    let mut report = bench::Report::default();
+    let mut regressions = 0;
+    for &solution in solutions {
+        let (source, input) = read_input(solution, None)?;
+        let timings = bench::bench(solution, &input, options).map_err(|e| in_source(e, &source))?;
+        for timing in timings {
+            let comparison = bench::Comparison::new(
+                &timing,
+                baseline
+                    .as_ref()
+                    .and_then(|b| b.get(timing.year, timing.day, timing.phase)),
+                options.threshold,
+            );
+            if matches!(comparison, bench::Comparison::Regression(_)) {
+                regressions += 1;
+            }
+            println!(
+                "{}",
+                bench::Line {
+                    timing: &timing,
+                    comparison
+                }
+            );
+            report.timings.push(timing);
+        }
+    }
+
+    if let Some(output) = &options.output {
+        report.save(output)?;
+    }
+    if regressions > 0 {
+        println!(
+            "{regressions} regressions beyond {}% of the baseline",
+            options.threshold
+        );
+    }
+    Ok(regressions == 0)
+}
+
+/// Runs and verifies all parts of the given solutions, printing a summary table and writing it
+/// where the options ask for. Returns whether every answer was right.
+fn run_all(solutions: &[&dyn DynSolution], options: &all::Options) -> Result<bool> {
+    let answers = answers::Answers::load(answers::MANIFEST)?;
+    let rows = all::run_all(
+        solutions,
+        &answers,
+        |solution| load_input(solution, None),
+        options.jobs,
+        cfg!(feature = "memory"),
+    )?;
+    print!("{}", all::table(&rows));
+    let summary = all::summarize(&rows);
+    println!("{summary}");
+
+    let write = |path: &Path, contents: &str| {
+        std::fs::write(path, contents)
+            .map_err(|e| Error::from(format!("cannot write {}: {e}", path.display())))
+    };
+    if let Some(path) = &options.markdown {
+        write(path, &all::markdown(&rows))?;
+    }
+    if let Some(path) = &options.json {
+        write(path, &all::to_json(&rows))?;
+    }
+    if options.readme {
+        let path = Path::new(all::README);
+        let readme = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+        write(path, &all::update_readme(&readme, &all::markdown(&rows))?)?;
+    }
+    Ok(summary.is_success())
+}
+
+/// The file that remembers when the puzzle site was last requested, so that the rate limit holds
+/// across runs: in the input directory if there is one, otherwise in the workspace root.
+fn timestamp_file() -> PathBuf {
+    std::env::var_os(input::INPUT_DIR_VAR)
+        .map_or_else(|| PathBuf::from(scaffold::ROOT), PathBuf::from)
+        .join(fetch::TIMESTAMP_FILE)
+}
+
+/// A client for the puzzle site that shares its rate limit with other runs of the runner.
+fn client() -> Result<fetch::Client> {
+    Ok(fetch::Client::from_env()?.with_timestamp_file(timestamp_file()))
+}
+
+/// Downloads the input of a day to where the runner looks for it, unless it is already there.
+fn fetch(year: u16, day: u8) -> Result<()> {
+    let Source::File(path) = input::locate(env!("CARGO_MANIFEST_DIR"), year, day)? else {
+        unreachable!("inputs are never located on stdin")
+    };
+    match client()?.fetch_input(year, day, &path)? {
+        fetch::Fetched::Cached(_) => println!("{} already exists", path.display()),
+        fetch::Fetched::Downloaded(_) => println!("saved {}", path.display()),
+    }
+    Ok(())
+}
+
+/// Submits an answer, computing it with the solution if none is given, and reports the verdict.
+fn run_submit(key: answers::Key, answer: Option<&str>, source: Option<&Source>) -> Result<bool> {
+    let (year, day, part) = key;
+    let answer = match answer {
+        Some(answer) => answer.to_string(),
+        None => {
+            let solution = solution::find(SOLUTIONS, year, day)
+                .ok_or_else(|| format!("no solution for {year} day {day}"))?;
+            solution.solve(load_input(solution, source)?.as_ref(), part)?
+        }
+    };
+
+    let response = submit::submit(&client()?, Path::new(submit::LOG), key, &answer)?;
+    println!("{year} day {day} part {part}: {answer} is {response}");
+    Ok(response == submit::Response::Judged(submit::Verdict::Correct))
+}
+
+/// Shows the progress of a long-running part on a line of stderr that is rewritten in place, if
+/// stderr is a terminal, and stops the part after `timeout`.
+fn live_progress(label: String, timeout: Option<Duration>) -> Progress {
+    let progress = Progress::new().with_timeout(timeout);
+    if std::io::stderr().is_terminal() {
+        progress.on_update(move |update| eprint!("\r\x1b[K{label}: {update}"))
+    } else {
+        progress
+    }
+}
+
+/// Runs the parts of a day, printing the work behind each answer as well if `explain` is set, and
+/// the memory each part allocated if the `memory` feature is enabled.
+fn run_day(
+    solution: &dyn DynSolution,
+    part: Option<Part>,
+    source: Option<&Source>,
+    explain: bool,
+    timeout: Option<Duration>,
+) -> Result<()> {
+    let (year, day) = (solution.year(), solution.day());
+    let input = load_input(solution, source)?;
+
+    let parts = part.map_or_else(|| solution.parts().to_vec(), |part| vec![part]);
+    for part in parts {
+        let label = format!("{year} day {day} part {part}");
+        let (answer, usage) = memory::measure(|| {
+            live_progress(label, timeout).run(|| solution.explain(input.as_ref(), part))
+        });
+        if std::io::stderr().is_terminal() {
+            eprint!("\r\x1b[K");
+        }
+        let answer = answer?;
+        if cfg!(feature = "memory") {
+            println!("{year} day {day} part {part}: {answer} ({usage})");
+        } else {
+            println!("{year} day {day} part {part}: {answer}");
+        }
+        if let Some(work) = answer.work.as_ref().filter(|_| explain) {
+            println!("    {work}");
+        }
+    }
+    Ok(())
+}
+
+fn execute(command: &Command) -> Result<ExitCode> {
+    match *command {
+        Command::Run {
+            year,
+            day,
+            part,
+            ref input,
+            explain,
+            timeout,
+            dump_input,
+        } => {
+            if dump_input && !cfg!(feature = "serde") {
+                return Err(format!("{DUMP_INPUT_ARG} requires the serde feature").into());
+            }
+            let timeout = timeout.map(Duration::from_secs);
+            for solution in select(Some(year), day)? {
+                if dump_input {
+                    let input = load_input(solution, input.as_ref())?;
+                    println!("{}", solution.input_json(input.as_ref())?);
+                } else {
+                    run_day(solution, part, input.as_ref(), explain, timeout)?;
+                }
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Verify { year, day } => {
+            let answers = answers::Answers::load(answers::MANIFEST)?;
+            let summary = verify::verify(
+                &select(year, day)?,
+                &answers,
+                |solution| load_input(solution, None),
+                |outcome| println!("{outcome}"),
+            );
+            println!("{summary}");
+            Ok(if summary.is_success() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            })
+        }
+        Command::All { year, ref options } => Ok(if run_all(&select(year, None)?, options)? {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        }),
+        Command::Bench {
+            year,
+            day,
+            ref options,
+        } => Ok(if run_bench(&select(year, day)?, options)? {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        }),
+        Command::Fetch { year, day } => {
+            fetch(year, day)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            ref answer,
+            ref input,
+        } => Ok(
+            if run_submit((year, day, part), answer.as_deref(), input.as_ref())? {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            },
+        ),
+        Command::New { year, day } => {
+            let root = Path::new(scaffold::ROOT);
+            for path in scaffold::scaffold(root, year, day)? {
+                println!(
+                    "wrote {}",
+                    path.strip_prefix(root).unwrap_or(&path).display()
+                );
+            }
+            println!("run `aoc fetch {year} {day}` to download the input");
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Generate {
+            year,
+            day,
+            seed,
+            size,
+        } => {
+            let solution = solution::find(SOLUTIONS, year, day)
+                .ok_or_else(|| format!("no solution for {year} day {day}"))?;
+            let size = size.unwrap_or_else(|| solution.input_size());
+            let input = solution
+                .generate(&mut Rng::new(seed), size)
+                .ok_or_else(|| format!("no input generator for {year} day {day}"))?;
+            print!("{input}");
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match parse_args(args).and_then(|command| execute(&command)) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(args(&["run", "2015", "7", "2"])).unwrap(),
+            Command::Run {
+                year: 2015,
+                day: Some(7),
+                part: Some(Part::Two),
+                input: None,
+                explain: false,
+                timeout: None,
+                dump_input: false
+            }
+        );
+        assert_eq!(
+            parse_args(args(&["run", "2015", "7"])).unwrap(),
+            Command::Run {
+                year: 2015,
+                day: Some(7),
+                part: None,
+                input: None,
+                explain: false,
+                timeout: None,
+                dump_input: false
+            }
+        );
+        assert_eq!(
+            parse_args(args(&["run", "2015"])).unwrap(),
+            Command::Run {
+                year: 2015,
+                day: None,
+                part: None,
+                input: None,
+                explain: false,
+                timeout: None,
+                dump_input: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_args_input() {
+        assert_eq!(
+            parse_args(args(&["run", "2015", "7", "--input", "-"])).unwrap(),
+            Command::Run {
+                year: 2015,
+                day: Some(7),
+                part: None,
+                input: Some(Source::Stdin),
+                explain: false,
+                timeout: None,
+                dump_input: false
+            }
+        );
+        assert!(parse_args(args(&["run", "2015", "--input", "in.txt"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_explain() {
+        assert_eq!(
+            parse_args(args(&["run", "2015", "9", "--explain"])).unwrap(),
+            Command::Run {
+                year: 2015,
+                day: Some(9),
+                part: None,
+                input: None,
+                explain: true,
+                timeout: None,
+                dump_input: false
+            }
+        );
+        assert!(parse_args(args(&["verify", "2015", "--explain"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_timeout() {
+        assert_eq!(
+            parse_args(args(&["run", "2015", "4", "--timeout", "30"])).unwrap(),
+            Command::Run {
+                year: 2015,
+                day: Some(4),
+                part: None,
+                input: None,
+                explain: false,
+                timeout: Some(30),
+                dump_input: false,
+            }
+        );
+        assert!(parse_args(args(&["run", "2015", "4", "--timeout", "soon"])).is_err());
+        assert!(parse_args(args(&["verify", "2015", "--timeout", "30"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_dump_input() {
+        assert_eq!(
+            parse_args(args(&["run", "2015", "7", "--dump-input", "--input", "-"])).unwrap(),
+            Command::Run {
+                year: 2015,
+                day: Some(7),
+                part: None,
+                input: Some(Source::Stdin),
+                explain: false,
+                timeout: None,
+                dump_input: true,
+            }
+        );
+        assert!(parse_args(args(&["run", "2015", "--dump-input"])).is_err());
+        assert!(parse_args(args(&["run", "2015", "7", "1", "--dump-input"])).is_err());
+        assert!(parse_args(args(&["all", "--dump-input"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_verify() {
+        assert_eq!(
+            parse_args(args(&["verify"])).unwrap(),
+            Command::Verify {
+                year: None,
+                day: None
+            }
+        );
+        assert_eq!(
+            parse_args(args(&["verify", "2015", "7"])).unwrap(),
+            Command::Verify {
+                year: Some(2015),
+                day: Some(7)
+            }
+        );
+        assert!(parse_args(args(&["verify", "2015", "7", "1"])).is_err());
+        assert!(parse_args(args(&["verify", "--input", "-"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_all() {
+        assert_eq!(
+            parse_args(args(&["all"])).unwrap(),
+            Command::All {
+                year: None,
+                options: all::Options::default()
+            }
+        );
+        assert_eq!(
+            parse_args(args(&[
+                "all",
+                "2015",
+                "--jobs",
+                "4",
+                "--markdown",
+                "status.md",
+                "--json",
+                "status.json",
+                "--readme"
+            ]))
+            .unwrap(),
+            Command::All {
+                year: Some(2015),
+                options: all::Options {
+                    jobs: 4,
+                    markdown: Some("status.md".into()),
+                    json: Some("status.json".into()),
+                    readme: true,
+                }
+            }
+        );
+        assert!(parse_args(args(&["all", "2015", "7"])).is_err());
+        assert!(parse_args(args(&["all", "--jobs", "many"])).is_err());
+        assert!(parse_args(args(&["verify", "--readme"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_bench() {
+        assert_eq!(
+            parse_args(args(&["bench", "2015", "4", "--samples", "3"])).unwrap(),
+            Command::Bench {
+                year: Some(2015),
+                day: Some(4),
+                options: bench::Options {
+                    samples: 3,
+                    ..bench::Options::default()
+                }
+            }
+        );
+        assert_eq!(
+            parse_args(args(&["bench"])).unwrap(),
+            Command::Bench {
+                year: None,
+                day: None,
+                options: bench::Options::default()
+            }
+        );
+        assert!(parse_args(args(&["bench", "2015", "4", "1"])).is_err());
+        assert!(parse_args(args(&["run", "2015", "--samples", "3"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_fetch() {
+        assert_eq!(
+            parse_args(args(&["fetch", "2015", "7"])).unwrap(),
+            Command::Fetch { year: 2015, day: 7 }
+        );
+        assert!(parse_args(args(&["fetch", "2015"])).is_err());
+        assert!(parse_args(args(&["fetch", "2015", "7", "--input", "-"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_submit() {
+        assert_eq!(
+            parse_args(args(&["submit", "2015", "7", "2", "14134"])).unwrap(),
+            Command::Submit {
+                year: 2015,
+                day: 7,
+                part: Part::Two,
+                answer: Some("14134".to_string()),
+                input: None
+            }
+        );
+        assert_eq!(
+            parse_args(args(&["submit", "2015", "7", "1", "--input", "-"])).unwrap(),
+            Command::Submit {
+                year: 2015,
+                day: 7,
+                part: Part::One,
+                answer: None,
+                input: Some(Source::Stdin)
+            }
+        );
+        assert!(parse_args(args(&["submit", "2015", "7"])).is_err());
+        assert!(parse_args(args(&["submit", "2015", "7", "3"])).is_err());
+        assert!(parse_args(args(&["submit", "2015", "7", "1", "5", "--input", "-"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_new() {
+        assert_eq!(
+            parse_args(args(&["new", "2016", "1"])).unwrap(),
+            Command::New { year: 2016, day: 1 }
+        );
+        assert!(parse_args(args(&["new", "2016"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_generate() {
+        assert_eq!(
+            parse_args(args(&["generate", "2015", "7"])).unwrap(),
+            Command::Generate {
+                year: 2015,
+                day: 7,
+                seed: 0,
+                size: None
+            }
+        );
+        assert_eq!(
+            parse_args(args(&["generate", "2015", "7", "--seed=3", "--size", "50"])).unwrap(),
+            Command::Generate {
+                year: 2015,
+                day: 7,
+                seed: 3,
+                size: Some(50)
+            }
+        );
+        assert!(parse_args(args(&["generate", "2015", "7", "--size", "x"])).is_err());
+        assert!(parse_args(args(&["run", "2015", "7", "--seed", "3"])).is_err());
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(select(Some(2015), None).unwrap().len(), 25);
+        assert_eq!(select(Some(2015), Some(7)).unwrap()[0].day(), 7);
+        assert!(select(Some(2015), Some(26)).is_err());
+        assert!(select(Some(1900), None).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_invalid() {
+        assert!(parse_args(args(&[])).is_err());
+        assert!(parse_args(args(&["run"])).is_err());
+        assert!(parse_args(args(&["walk", "2015"])).is_err());
+        assert!(parse_args(args(&["run", "x"])).is_err());
+        assert!(parse_args(args(&["run", "2015", "x"])).is_err());
+        assert!(parse_args(args(&["run", "2015", "7", "3"])).is_err());
+        assert!(parse_args(args(&["run", "2015", "7", "2", "1"])).is_err());
+    }
+}
@@ -0,0 +1,74 @@
+use common::solution::{DynSolution, Registered};
+
+/// All solutions known to the runner.
+pub const SOLUTIONS: &[&dyn DynSolution] = &[
+    &Registered::<day_01_2::Day01>::new(),
+    &Registered::<day_02_2::Day02>::new(),
+    &Registered::<day_03_2::Day03>::new(),
+    &Registered::<day_04_2::Day04>::new(),
+    &Registered::<day_05_2::Day05>::new(),
+    &Registered::<day_06_2::Day06>::new(),
+    &Registered::<day_07_2::Day07>::new(),
+    &Registered::<day_08_2::Day08>::new(),
+    &Registered::<day_09_2::Day09>::new(),
+    &Registered::<day_10_2::Day10>::new(),
+    &Registered::<day_11_2::Day11>::new(),
+    &Registered::<day_12_2::Day12>::new(),
+    &Registered::<day_13_2::Day13>::new(),
+    &Registered::<day_14_2::Day14>::new(),
+    &Registered::<day_15_2::Day15>::new(),
+    &Registered::<day_16_2::Day16>::new(),
+    &Registered::<day_17_2::Day17>::new(),
+    &Registered::<day_18_2::Day18>::new(),
+    &Registered::<day_19_2::Day19>::new(),
+    &Registered::<day_20_2::Day20>::new(),
+    &Registered::<day_21_2::Day21>::new(),
+    &Registered::<day_22_2::Day22>::new(),
+    &Registered::<day_23_2::Day23>::new(),
+    &Registered::<day_24_2::Day24>::new(),
+    &Registered::<day_25_1::Day25>::new(),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::generate::Rng;
+
+    #[test]
+    fn test_registry_is_complete() {
+        for day in 1..=25 {
+            assert!(common::solution::find(SOLUTIONS, 2015, day).is_some());
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_parse() {
+        for solution in SOLUTIONS {
+            for seed in 0..3 {
+                // days scaffolded by `aoc new` have no generator until one is written
+                let Some(input) = solution.generate(&mut Rng::new(seed), solution.input_size())
+                else {
+                    assert_ne!(
+                        solution.year(),
+                        2015,
+                        "every 2015 day has an input generator"
+                    );
+                    continue;
+                };
+                if let Err(e) = solution.parse(&input) {
+                    panic!(
+                        "{} day {} cannot parse its input for seed {seed}: {e}",
+                        solution.year(),
+                        solution.day()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_registry_is_sorted() {
+        let keys: Vec<_> = SOLUTIONS.iter().map(|s| (s.year(), s.day())).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
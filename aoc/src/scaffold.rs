@@ -0,0 +1,382 @@
+use std::path::{Path, PathBuf};
+
+use common::solution::Result;
+
+/// The workspace root, relative to the runner crate.
+pub const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// The templates of the files of a new day, relative to the directory of its year.
+const TEMPLATES: [(&str, &str); 7] = [
+    (
+        "day_{{dd}}_1/Cargo.toml",
+        include_str!("../templates/part_1/Cargo.toml.in"),
+    ),
+    (
+        "day_{{dd}}_1/src/lib.rs",
+        include_str!("../templates/part_1/lib.rs.in"),
+    ),
+    (
+        "day_{{dd}}_1/src/main.rs",
+        include_str!("../templates/part_1/main.rs.in"),
+    ),
+    (
+        "day_{{dd}}_2/Cargo.toml",
+        include_str!("../templates/part_2/Cargo.toml.in"),
+    ),
+    (
+        "day_{{dd}}_2/src/lib.rs",
+        include_str!("../templates/part_2/lib.rs.in"),
+    ),
+    (
+        "day_{{dd}}_2/src/main.rs",
+        include_str!("../templates/part_2/main.rs.in"),
+    ),
+    (
+        "day_{{dd}}_2/examples.txt",
+        include_str!("../templates/part_2/examples.txt.in"),
+    ),
+];
+
+/// The templates of the last day, which only has a first part, so a single crate implements its
+/// `Solution`.
+const LAST_DAY_TEMPLATES: [(&str, &str); 4] = [
+    (
+        "day_{{dd}}_1/Cargo.toml",
+        include_str!("../templates/last_day/Cargo.toml.in"),
+    ),
+    (
+        "day_{{dd}}_1/src/lib.rs",
+        include_str!("../templates/last_day/lib.rs.in"),
+    ),
+    (
+        "day_{{dd}}_1/src/main.rs",
+        include_str!("../templates/last_day/main.rs.in"),
+    ),
+    (
+        "day_{{dd}}_1/examples.txt",
+        include_str!("../templates/last_day/examples.txt.in"),
+    ),
+];
+
+/// The last day of a year, the only one without a second part.
+const LAST_DAY: u8 = 25;
+
+/// The part whose crate implements the `Solution` of a day.
+fn solution_part(day: u8) -> u8 {
+    if day == LAST_DAY {
+        1
+    } else {
+        2
+    }
+}
+
+/// The name of the crate of a part.
+///
+/// Package names have to be unique within the workspace, so only 2015 gets away without the year.
+fn crate_name(year: u16, day: u8, part: u8) -> String {
+    if year == 2015 {
+        format!("day_{day:02}_{part}")
+    } else {
+        format!("y{year}_day_{day:02}_{part}")
+    }
+}
+
+/// The year and day of a crate name as returned by `crate_name`.
+fn parse_crate_name(name: &str) -> Option<(u16, u8)> {
+    let (year, rest) = match name.strip_prefix('y') {
+        Some(rest) => {
+            let (year, rest) = rest.split_once('_')?;
+            (year.parse().ok()?, rest)
+        }
+        None => (2015, name),
+    };
+    let day = rest.strip_prefix("day_")?.get(..2)?.parse().ok()?;
+    Some((year, day))
+}
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+        .replace("{{dd}}", &format!("{day:02}"))
+        .replace("{{crate_1}}", &crate_name(year, day, 1))
+        .replace("{{crate_2}}", &crate_name(year, day, 2))
+}
+
+fn read(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()).into())
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+    }
+    std::fs::write(path, contents)
+        .map_err(|e| format!("cannot write {}: {e}", path.display()).into())
+}
+
+/// Adds `"<year>/*"` to the workspace members, keeping the years sorted.
+fn add_member(manifest: &str, year: u16) -> Result<Option<String>> {
+    let member = format!("\"{year}/*\",");
+    let mut lines: Vec<_> = manifest.lines().map(str::to_string).collect();
+    if lines.iter().any(|line| line.trim() == member) {
+        return Ok(None);
+    }
+
+    let start = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("members = ["))
+        .ok_or("no workspace members in the manifest")?;
+    let position = lines[start + 1..]
+        .iter()
+        .position(|line| {
+            let line = line.trim();
+            line.starts_with(']') || !line.starts_with("\"20") || line > member.as_str()
+        })
+        .map_or(lines.len(), |offset| start + 1 + offset);
+    lines.insert(position, format!("    {member}"));
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+/// Adds the `serde` feature of a crate to the runner's `serde` feature, keeping it sorted.
+///
+/// Manifests without a `serde` feature are returned unchanged.
+fn add_feature(manifest: &str, name: &str) -> String {
+    let feature = format!("\"{name}/serde\",");
+    let mut lines: Vec<_> = manifest.lines().map(str::to_string).collect();
+    let Some(start) = lines.iter().position(|line| line.starts_with("serde = [")) else {
+        return manifest.to_string();
+    };
+    let position = lines[start + 1..]
+        .iter()
+        .position(|line| {
+            let line = line.trim();
+            line.starts_with(']') || line > feature.as_str()
+        })
+        .map_or(lines.len(), |offset| start + 1 + offset);
+    lines.insert(position, format!("    {feature}"));
+    lines.join("\n") + "\n"
+}
+
+/// Adds the solution of a day to the runner's registry, keeping it sorted.
+fn add_registration(registry: &str, year: u16, day: u8) -> Result<String> {
+    let mut lines: Vec<_> = registry.lines().map(str::to_string).collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const SOLUTIONS"))
+        .ok_or("no solutions in the registry")?;
+    let position = lines[start + 1..]
+        .iter()
+        .position(|line| {
+            let key = line
+                .trim()
+                .strip_prefix("&Registered::<")
+                .and_then(|rest| parse_crate_name(rest.split("::").next()?));
+            key.is_none_or(|key| key > (year, day))
+        })
+        .map(|offset| start + 1 + offset)
+        .ok_or("unterminated solutions in the registry")?;
+    lines.insert(
+        position,
+        format!(
+            "    &Registered::<{}::Day{day:02}>::new(),",
+            crate_name(year, day, solution_part(day))
+        ),
+    );
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates the crate pair of a new day below `root` and registers it with the workspace and the
+/// runner, returning the files that were created or changed.
+///
+/// # Errors
+///
+/// Returns an `Err` if the day already exists or a file cannot be read or written.
+pub fn scaffold(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>> {
+    if !(1..=LAST_DAY).contains(&day) {
+        return Err(format!("invalid day: {day}").into());
+    }
+    let year_dir = root.join(year.to_string());
+    for part in 1..=2 {
+        let dir = year_dir.join(format!("day_{day:02}_{part}"));
+        if dir.exists() {
+            return Err(format!("{} already exists", dir.display()).into());
+        }
+    }
+
+    let templates: &[(&str, &str)] = if day == LAST_DAY {
+        &LAST_DAY_TEMPLATES
+    } else {
+        &TEMPLATES
+    };
+    let mut changed = Vec::new();
+    for &(path, template) in templates {
+        let path = year_dir.join(render(path, year, day));
+        write(&path, &render(template, year, day))?;
+        changed.push(path);
+    }
+    let placeholder = year_dir.join(format!("day_{day:02}_1/input/input.txt"));
+    write(&placeholder, "")?;
+    changed.push(placeholder);
+
+    let manifest = root.join("Cargo.toml");
+    if let Some(updated) = add_member(&read(&manifest)?, year)? {
+        write(&manifest, &updated)?;
+        changed.push(manifest);
+    }
+
+    let part = solution_part(day);
+    let runner_manifest = root.join("aoc/Cargo.toml");
+    let mut contents = add_feature(&read(&runner_manifest)?, &crate_name(year, day, part));
+    if !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+    contents.push_str(&format!(
+        "{}.path = \"../{year}/day_{day:02}_{part}\"\n",
+        crate_name(year, day, part)
+    ));
+    write(&runner_manifest, &contents)?;
+    changed.push(runner_manifest);
+
+    let registry = root.join("aoc/src/registry.rs");
+    write(&registry, &add_registration(&read(&registry)?, year, day)?)?;
+    changed.push(registry);
+
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crate_name() {
+        assert_eq!(crate_name(2015, 7, 1), "day_07_1");
+        assert_eq!(crate_name(2016, 12, 2), "y2016_day_12_2");
+        assert_eq!(parse_crate_name("day_25_1"), Some((2015, 25)));
+        assert_eq!(parse_crate_name("y2016_day_12_2"), Some((2016, 12)));
+        assert_eq!(parse_crate_name("common"), None);
+    }
+
+    #[test]
+    fn test_add_member() {
+        let manifest = "[workspace]\n\nmembers = [\n    \"2015/*\",\n    \"aoc\",\n]\n";
+        let updated = add_member(manifest, 2016).unwrap().unwrap();
+        assert_eq!(
+            updated,
+            "[workspace]\n\nmembers = [\n    \"2015/*\",\n    \"2016/*\",\n    \"aoc\",\n]\n"
+        );
+        assert_eq!(add_member(&updated, 2016).unwrap(), None);
+        assert!(add_member(&updated, 2014)
+            .unwrap()
+            .unwrap()
+            .contains("[\n    \"2014/*\",\n    \"2015/*\""));
+    }
+
+    #[test]
+    fn test_add_feature() {
+        let manifest = "[features]\nserde = [\n    \"common/serde\",\n    \
+                        \"day_25_1/serde\",\n]\n\n[dependencies]\n";
+        let updated = add_feature(manifest, "day_24_2");
+        let lines: Vec<_> = updated.lines().map(str::trim).collect();
+        assert_eq!(
+            lines[3..6],
+            ["\"day_24_2/serde\",", "\"day_25_1/serde\",", "]"]
+        );
+        assert!(add_feature(&updated, "y2016_day_01_2")
+            .contains("\"day_25_1/serde\",\n    \"y2016_day_01_2/serde\",\n]"));
+        assert_eq!(
+            add_feature("[dependencies]\n", "day_24_2"),
+            "[dependencies]\n"
+        );
+    }
+
+    #[test]
+    fn test_add_registration() {
+        let registry = "pub const SOLUTIONS: &[&dyn DynSolution] = &[\n    \
+                        &Registered::<day_25_1::Day25>::new(),\n    \
+                        &Registered::<y2016_day_05_2::Day05>::new(),\n];\n";
+        let updated = add_registration(registry, 2016, 3).unwrap();
+        let lines: Vec<_> = updated.lines().map(str::trim).collect();
+        assert_eq!(
+            lines[1..4],
+            [
+                "&Registered::<day_25_1::Day25>::new(),",
+                "&Registered::<y2016_day_03_2::Day03>::new(),",
+                "&Registered::<y2016_day_05_2::Day05>::new(),",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        write(
+            &root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"2015/*\",\n    \"aoc\",\n]\n",
+        )
+        .unwrap();
+        write(&root.join("aoc/Cargo.toml"), "[dependencies]\n").unwrap();
+        write(
+            &root.join("aoc/src/registry.rs"),
+            "pub const SOLUTIONS: &[&dyn DynSolution] = &[\n];\n",
+        )
+        .unwrap();
+
+        let changed = scaffold(&root, 2016, 4).unwrap();
+        assert_eq!(changed.len(), 11);
+        let lib = read(&root.join("2016/day_04_2/src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day04"));
+        assert!(lib.contains("const YEAR: u16 = 2016;"));
+        assert!(lib.contains("y2016_day_04_1::parse(input)"));
+        assert_eq!(
+            read(&root.join("2016/day_04_1/input/input.txt")).unwrap(),
+            ""
+        );
+        assert!(read(&root.join("Cargo.toml"))
+            .unwrap()
+            .contains("\"2016/*\","));
+        assert_eq!(
+            read(&root.join("aoc/Cargo.toml")).unwrap(),
+            "[dependencies]\ny2016_day_04_2.path = \"../2016/day_04_2\"\n"
+        );
+
+        assert!(scaffold(&root, 2016, 4).is_err());
+        assert!(scaffold(&root, 2016, 26).is_err());
+    }
+
+    #[test]
+    fn test_scaffold_last_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-last-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        write(
+            &root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"2016/*\",\n    \"aoc\",\n]\n",
+        )
+        .unwrap();
+        write(&root.join("aoc/Cargo.toml"), "[dependencies]\n").unwrap();
+        write(
+            &root.join("aoc/src/registry.rs"),
+            "pub const SOLUTIONS: &[&dyn DynSolution] = &[\n];\n",
+        )
+        .unwrap();
+
+        let changed = scaffold(&root, 2016, 25).unwrap();
+        assert_eq!(changed.len(), 7);
+        assert!(!root.join("2016/day_25_2").exists());
+        let lib = read(&root.join("2016/day_25_1/src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day25"));
+        assert!(lib.contains("const PARTS: &'static [Part] = &[Part::One];"));
+        let examples = read(&root.join("2016/day_25_1/examples.txt")).unwrap();
+        assert!(!examples.contains("part2"));
+        assert_eq!(
+            read(&root.join("aoc/Cargo.toml")).unwrap(),
+            "[dependencies]\ny2016_day_25_1.path = \"../2016/day_25_1\"\n"
+        );
+        assert!(read(&root.join("aoc/src/registry.rs"))
+            .unwrap()
+            .contains("&Registered::<y2016_day_25_1::Day25>::new(),"));
+    }
+}
@@ -0,0 +1,380 @@
+use std::fmt::Display;
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+use common::fetch::Client;
+use common::solution::{Error, Result};
+
+use crate::answers::Key;
+
+/// The log of submitted answers, relative to the runner crate.
+pub const LOG: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../submissions.txt");
+
+/// How the puzzle site judged an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too-high"),
+            Self::TooLow => write!(f, "too-low"),
+            Self::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "too-high" => Ok(Self::TooHigh),
+            "too-low" => Ok(Self::TooLow),
+            "wrong" => Ok(Self::Wrong),
+            _ => Err(format!("invalid verdict: {s}").into()),
+        }
+    }
+}
+
+/// The response of the puzzle site to a submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Response {
+    Judged(Verdict),
+    /// Another answer was submitted too recently, the wait is given if the site told it.
+    RateLimited(Option<Duration>),
+    /// The part is not unlocked or was already solved.
+    WrongLevel,
+    Unknown,
+}
+
+/// Parses a wait like `1m 23s` or `37s`.
+fn parse_wait(wait: &str) -> Option<Duration> {
+    wait.split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "h" => Some(number * 3_600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+impl Response {
+    /// Interprets the HTML page returned for a submission.
+    #[must_use]
+    pub fn parse(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Self::Judged(Verdict::Correct)
+        } else if page.contains("That's not the right answer") {
+            Self::Judged(if page.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if page.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            })
+        } else if page.contains("You gave an answer too recently") {
+            let wait = page
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .and_then(|(wait, _)| parse_wait(wait));
+            Self::RateLimited(wait)
+        } else if page.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+impl Display for Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Judged(Verdict::Correct) => write!(f, "correct"),
+            Self::Judged(Verdict::TooHigh) => write!(f, "wrong, too high"),
+            Self::Judged(Verdict::TooLow) => write!(f, "wrong, too low"),
+            Self::Judged(Verdict::Wrong) => write!(f, "wrong"),
+            Self::RateLimited(Some(wait)) => {
+                write!(f, "rate limited, try again in {}s", wait.as_secs())
+            }
+            Self::RateLimited(None) => write!(f, "rate limited"),
+            Self::WrongLevel => write!(f, "not the current level, is it already solved?"),
+            Self::Unknown => write!(f, "unrecognized response"),
+        }
+    }
+}
+
+/// A judged submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub key: Key,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (year, day, part) = self.key;
+        write!(f, "{year} {day:2} {part} {} {}", self.verdict, self.answer)
+    }
+}
+
+/// All judged submissions so far.
+///
+/// The log is a text file with one `<year> <day> <part> <verdict> <answer>` entry per line, which
+/// is only ever appended to. Empty lines and lines starting with `#` are ignored.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Log {
+    submissions: Vec<Submission>,
+}
+
+impl Log {
+    /// Parses a log.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if a line is malformed.
+    pub fn parse(log: &str) -> Result<Self> {
+        let mut submissions = Vec::new();
+        for (number, line) in log.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("invalid submission on line {}: {line}", number + 1);
+            let fields: Vec<_> = line.split_whitespace().collect();
+            let [year, day, part, verdict, answer] = fields[..] else {
+                return Err(invalid().into());
+            };
+            submissions.push(Submission {
+                key: (
+                    year.parse().map_err(|_| invalid())?,
+                    day.parse().map_err(|_| invalid())?,
+                    part.parse().map_err(|_| invalid())?,
+                ),
+                answer: answer.to_string(),
+                verdict: verdict.parse().map_err(|_| invalid())?,
+            });
+        }
+        Ok(Self { submissions })
+    }
+
+    /// Reads the log from disk, treating a missing file as empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the file cannot be read or parsed.
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(log) => Self::parse(&log),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("cannot read {}: {e}", path.display()).into()),
+        }
+    }
+
+    /// Adds a submission to the log and appends it to the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the file cannot be written.
+    pub fn record(&mut self, path: &Path, submission: Submission) -> Result<()> {
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{submission}"))
+            .map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    /// Checks that an answer is worth submitting given the previous submissions of its part.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` explaining why the answer is refused if it is empty or contains
+    /// whitespace (which the log could not hold), the part was solved already, the answer was
+    /// rejected before or it is not between the known bounds.
+    pub fn check(&self, key: Key, answer: &str) -> Result<()> {
+        if answer.is_empty() || answer.contains(char::is_whitespace) {
+            return Err(format!("invalid answer: {answer:?}").into());
+        }
+        let number = answer.parse::<i128>().ok();
+        for submission in self.submissions.iter().filter(|s| s.key == key) {
+            let previous = &submission.answer;
+            let bound = previous.parse::<i128>().ok().zip(number);
+            match submission.verdict {
+                Verdict::Correct => {
+                    return Err(format!("already solved, the answer is {previous}").into())
+                }
+                _ if previous == answer => {
+                    return Err(format!("{answer} was already rejected").into())
+                }
+                Verdict::TooHigh if bound.is_some_and(|(high, n)| n >= high) => {
+                    return Err(format!("{answer} is too high, {previous} already was").into())
+                }
+                Verdict::TooLow if bound.is_some_and(|(low, n)| n <= low) => {
+                    return Err(format!("{answer} is too low, {previous} already was").into())
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submits an answer unless the log refuses it, recording the verdict.
+///
+/// # Errors
+///
+/// Returns an `Err` if the answer is refused, the request fails or the log cannot be written.
+pub fn submit(client: &Client, log_path: &Path, key: Key, answer: &str) -> Result<Response> {
+    let mut log = Log::load(log_path)?;
+    log.check(key, answer)?;
+
+    let (year, day, part) = key;
+    let level = part.number().to_string();
+    let page = client.post_form(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    let response = Response::parse(&page);
+    if let Response::Judged(verdict) = response {
+        log.record(
+            log_path,
+            Submission {
+                key,
+                answer: answer.to_string(),
+                verdict,
+            },
+        )?;
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use common::fetch::stub;
+    use common::solution::Part;
+
+    const KEY: Key = (2015, 7, Part::Two);
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            Response::parse("<p>That's the right answer!  You are one gold star closer.</p>"),
+            Response::Judged(Verdict::Correct)
+        );
+        assert_eq!(
+            Response::parse("<p>That's not the right answer; your answer is too high.</p>"),
+            Response::Judged(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Response::parse("<p>That's not the right answer; your answer is too low.</p>"),
+            Response::Judged(Verdict::TooLow)
+        );
+        assert_eq!(
+            Response::parse("<p>That's not the right answer.  If you're stuck, ...</p>"),
+            Response::Judged(Verdict::Wrong)
+        );
+        assert_eq!(
+            Response::parse(
+                "<p>You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 23s left to wait. </p>"
+            ),
+            Response::RateLimited(Some(Duration::from_secs(83)))
+        );
+        assert_eq!(
+            Response::parse("<p>You gave an answer too recently.</p>"),
+            Response::RateLimited(None)
+        );
+        assert_eq!(
+            Response::parse("<p>You don't seem to be solving the right level.</p>"),
+            Response::WrongLevel
+        );
+        assert_eq!(Response::parse("<p>Hello</p>"), Response::Unknown);
+    }
+
+    #[test]
+    fn test_check() {
+        let log = Log::parse(
+            "2015 7 2 too-high 500\n2015 7 2 too-low 100\n2015 7 2 wrong 300\n2015 7 1 correct 9",
+        )
+        .unwrap();
+        assert!(log.check(KEY, "200").is_ok());
+        assert!(log.check(KEY, "300").is_err());
+        assert!(log.check(KEY, "500").is_err());
+        assert!(log.check(KEY, "600").is_err());
+        assert!(log.check(KEY, "100").is_err());
+        assert!(log.check(KEY, "-5").is_err());
+        assert!(log.check(KEY, "abc").is_ok());
+        assert!(log.check((2015, 7, Part::One), "10").is_err());
+        assert!(log.check((2015, 8, Part::Two), "300").is_ok());
+        assert!(log.check((2015, 8, Part::Two), "").is_err());
+        assert!(log.check((2015, 8, Part::Two), "12 34").is_err());
+    }
+
+    #[test]
+    fn test_parse_log_invalid() {
+        assert!(Log::parse("2015 7 2 300").is_err());
+        assert!(Log::parse("2015 7 2 maybe 300").is_err());
+        assert!(Log::parse("2015 7 3 wrong 300").is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = stub::serve(|request| {
+            let page = if request.body.contains("answer=42") {
+                "That's the right answer!"
+            } else {
+                "That's not the right answer; your answer is too low."
+            };
+            (200, page.to_string())
+        });
+        let client = Client::new("secret")
+            .with_base_url(server.url.clone())
+            .with_min_interval(Duration::ZERO);
+        let log_path =
+            std::env::temp_dir().join(format!("aoc-submissions-{}.txt", std::process::id()));
+        let _ = std::fs::remove_file(&log_path);
+
+        assert_eq!(
+            submit(&client, &log_path, KEY, "41").unwrap(),
+            Response::Judged(Verdict::TooLow)
+        );
+        assert!(submit(&client, &log_path, KEY, "40").is_err());
+        assert_eq!(
+            submit(&client, &log_path, KEY, "42").unwrap(),
+            Response::Judged(Verdict::Correct)
+        );
+        assert!(submit(&client, &log_path, KEY, "43").is_err());
+
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2015/day/7/answer");
+        assert_eq!(requests[0].body, "level=2&answer=41");
+        assert_eq!(
+            std::fs::read_to_string(&log_path).unwrap(),
+            "2015  7 2 too-low 41\n2015  7 2 correct 42\n"
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use std::any::Any;
+use std::fmt::Display;
+
+use common::solution::{DynSolution, Part, Result};
+
+use crate::answers::{Answers, Key, Verification};
+
+/// The status of a single verified part.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved {
+        answer: String,
+        verification: Verification,
+    },
+    Error(String),
+}
+
+impl Status {
+    /// Checks the answer to a part, or the error that kept it from being solved.
+    #[must_use]
+    pub fn new(answers: &Answers, key: Key, answer: Result<String>) -> Self {
+        match answer {
+            Ok(answer) => Self::Solved {
+                verification: Verification::check(answers, key, &answer),
+                answer,
+            },
+            Err(e) => Self::Error(e.to_string()),
+        }
+    }
+}
+
+/// The outcome of verifying a single part.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Outcome {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub status: Status,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {:2} part {}: ", self.year, self.day, self.part)?;
+        match &self.status {
+            Status::Solved {
+                answer,
+                verification,
+            } => write!(f, "{answer} ({verification})"),
+            Status::Error(e) => write!(f, "ERROR ({e})"),
+        }
+    }
+}
+
+/// Counts of all verified parts.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub missing: usize,
+    pub errors: usize,
+}
+
+impl Summary {
+    pub fn add(&mut self, outcome: &Outcome) {
+        match outcome.status {
+            Status::Solved {
+                verification: Verification::Pass,
+                ..
+            } => self.passed += 1,
+            Status::Solved {
+                verification: Verification::Fail { .. },
+                ..
+            } => self.failed += 1,
+            Status::Solved {
+                verification: Verification::Missing,
+                ..
+            } => self.missing += 1,
+            Status::Error(_) => self.errors += 1,
+        }
+    }
+
+    /// Whether no answer drifted from the manifest and no solution failed.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.failed == 0 && self.errors == 0
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} missing, {} errors",
+            self.passed, self.failed, self.missing, self.errors
+        )
+    }
+}
+
+/// Runs all parts of the given solutions and checks their answers against the manifest.
+///
+/// Every outcome is reported as soon as it is known.
+pub fn verify(
+    solutions: &[&dyn DynSolution],
+    answers: &Answers,
+    load: impl Fn(&dyn DynSolution) -> Result<Box<dyn Any>>,
+    mut report: impl FnMut(&Outcome),
+) -> Summary {
+    let mut summary = Summary::default();
+    for &solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let input = load(solution);
+        for &part in solution.parts() {
+            let status = match &input {
+                Ok(input) => Status::new(
+                    answers,
+                    (year, day, part),
+                    solution.solve(input.as_ref(), part),
+                ),
+                Err(e) => Status::Error(e.to_string()),
+            };
+            let outcome = Outcome {
+                year,
+                day,
+                part,
+                status,
+            };
+            summary.add(&outcome);
+            report(&outcome);
+        }
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::solution::{Registered, Solution};
+
+    struct Double;
+
+    impl Solution for Double {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+
+        type Input = u32;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(input: &str) -> Result<u32> {
+            Ok(input.trim().parse()?)
+        }
+
+        fn part1(input: &u32) -> Result<u32> {
+            Ok(input * 2)
+        }
+
+        fn part2(_: &u32) -> Result<u32> {
+            Err("unsolvable".into())
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let solution = Registered::<Double>::new();
+        let answers = Answers::parse("2015 1 1 42").unwrap();
+
+        let mut outcomes = Vec::new();
+        let summary = verify(
+            &[&solution],
+            &answers,
+            |s| s.parse("21"),
+            |outcome| outcomes.push(outcome.to_string()),
+        );
+        assert_eq!(
+            summary,
+            Summary {
+                passed: 1,
+                errors: 1,
+                ..Summary::default()
+            }
+        );
+        assert!(!summary.is_success());
+        assert_eq!(
+            outcomes,
+            [
+                "2015 day  1 part 1: 42 (pass)",
+                "2015 day  1 part 2: ERROR (unsolvable)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_drift() {
+        let solution = Registered::<Double>::new();
+        let answers = Answers::parse("2015 1 1 42").unwrap();
+
+        let mut outcomes = Vec::new();
+        let summary = verify(
+            &[&solution],
+            &answers,
+            |s| s.parse("20"),
+            |outcome| {
+                outcomes.push(outcome.to_string());
+            },
+        );
+        assert_eq!(summary.failed, 1);
+        assert_eq!(outcomes[0], "2015 day  1 part 1: 40 (FAIL, expected 42)");
+    }
+
+    #[test]
+    fn test_verify_missing_input() {
+        let solution = Registered::<Double>::new();
+        let summary = verify(
+            &[&solution],
+            &Answers::default(),
+            |_| Err("no input".into()),
+            |_| {},
+        );
+        assert_eq!(summary.errors, 2);
+    }
+}
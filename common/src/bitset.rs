@@ -0,0 +1,314 @@
+use std::fmt::Debug;
+
+use crate::combinatorics::{k_subsets, mask_indices, subsets};
+
+const WORD_BITS: usize = u64::BITS as usize;
+const INLINE_WORDS: usize = 2;
+
+/// The bits are kept inline as long as they fit, so each set has exactly one representation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Storage {
+    Inline(u128),
+    Heap(Vec<u64>),
+}
+
+/// A set of small non-negative integers, stored as one bit per possible element.
+///
+/// Sets whose elements are all below 128 are stored inline without allocating, larger ones on the
+/// heap.
+///
+/// # Examples
+///
+/// ```
+/// use common::BitSet;
+///
+/// let mut set: BitSet = [1, 4, 200].into_iter().collect();
+/// assert!(set.contains(200));
+/// assert!(set.insert(9));
+/// assert!(set.remove(200));
+/// assert_eq!(set.len(), 3);
+/// assert_eq!(set.iter().collect::<Vec<_>>(), [1, 4, 9]);
+/// assert_eq!(set, BitSet::from(0b10_0001_0010_u64));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    storage: Storage,
+}
+
+impl BitSet {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            storage: Storage::Inline(0),
+        }
+    }
+
+    /// The set of all integers below `n`.
+    #[must_use]
+    pub fn full(n: usize) -> Self {
+        let mut words = vec![u64::MAX; n / WORD_BITS];
+        if !n.is_multiple_of(WORD_BITS) {
+            words.push((1 << (n % WORD_BITS)) - 1);
+        }
+        Self::from_words(words)
+    }
+
+    fn from_words(mut words: Vec<u64>) -> Self {
+        while words.last() == Some(&0) {
+            words.pop();
+        }
+        if words.len() <= INLINE_WORDS {
+            let bits = words
+                .iter()
+                .rev()
+                .fold(0, |bits, &word| (bits << WORD_BITS) | u128::from(word));
+            Self {
+                storage: Storage::Inline(bits),
+            }
+        } else {
+            Self {
+                storage: Storage::Heap(words),
+            }
+        }
+    }
+
+    fn word_count(&self) -> usize {
+        match &self.storage {
+            Storage::Inline(_) => INLINE_WORDS,
+            Storage::Heap(words) => words.len(),
+        }
+    }
+
+    fn word(&self, index: usize) -> u64 {
+        match &self.storage {
+            #[allow(clippy::cast_possible_truncation)]
+            Storage::Inline(bits) if index < INLINE_WORDS => (bits >> (index * WORD_BITS)) as u64,
+            Storage::Inline(_) => 0,
+            Storage::Heap(words) => words.get(index).copied().unwrap_or(0),
+        }
+    }
+
+    fn words(&self) -> Vec<u64> {
+        (0..self.word_count()).map(|w| self.word(w)).collect()
+    }
+
+    fn combine(&self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        let count = self.word_count().max(other.word_count());
+        Self::from_words(
+            (0..count)
+                .map(|w| op(self.word(w), other.word(w)))
+                .collect(),
+        )
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: usize) -> bool {
+        self.word(value / WORD_BITS) & (1 << (value % WORD_BITS)) != 0
+    }
+
+    /// Adds a value to the set, returning whether it was newly added.
+    pub fn insert(&mut self, value: usize) -> bool {
+        if self.contains(value) {
+            return false;
+        }
+        match &mut self.storage {
+            Storage::Inline(bits) if value < INLINE_WORDS * WORD_BITS => *bits |= 1 << value,
+            _ => {
+                let mut words = self.words();
+                let word = value / WORD_BITS;
+                if words.len() <= word {
+                    words.resize(word + 1, 0);
+                }
+                words[word] |= 1 << (value % WORD_BITS);
+                *self = Self::from_words(words);
+            }
+        }
+        true
+    }
+
+    /// Removes a value from the set, returning whether it was present.
+    pub fn remove(&mut self, value: usize) -> bool {
+        if !self.contains(value) {
+            return false;
+        }
+        match &mut self.storage {
+            Storage::Inline(bits) => *bits &= !(1 << value),
+            Storage::Heap(words) => {
+                words[value / WORD_BITS] &= !(1 << (value % WORD_BITS));
+                *self = Self::from_words(std::mem::take(words));
+            }
+        }
+        true
+    }
+
+    /// The number of values in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match &self.storage {
+            Storage::Inline(bits) => bits.count_ones() as usize,
+            Storage::Heap(words) => words.iter().map(|w| w.count_ones() as usize).sum(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.storage == Storage::Inline(0)
+    }
+
+    /// The values in the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.word_count())
+            .flat_map(move |w| mask_indices(self.word(w)).map(move |bit| w * WORD_BITS + bit))
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a | b)
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & b)
+    }
+
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    #[must_use]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        (0..self.word_count()).all(|w| self.word(w) & !other.word(w) == 0)
+    }
+
+    /// All subsets of this set, ordered by size and then lexicographically by their values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common::BitSet;
+    ///
+    /// let set: BitSet = [2, 5].into_iter().collect();
+    /// let subsets: Vec<Vec<_>> = set.subsets().map(|s| s.iter().collect()).collect();
+    /// assert_eq!(subsets, [vec![], vec![2], vec![5], vec![2, 5]]);
+    /// ```
+    pub fn subsets(&self) -> impl Iterator<Item = Self> {
+        let values: Vec<_> = self.iter().collect();
+        subsets(values.len()).map(move |indices| indices.iter().map(|&i| values[i]).collect())
+    }
+
+    /// All subsets of this set with exactly `k` values, in the order of [`BitSet::subsets`].
+    pub fn k_subsets(&self, k: usize) -> impl Iterator<Item = Self> {
+        let values: Vec<_> = self.iter().collect();
+        k_subsets(values.len(), k).map(move |indices| indices.iter().map(|&i| values[i]).collect())
+    }
+}
+
+impl Default for BitSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for BitSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl From<u64> for BitSet {
+    fn from(mask: u64) -> Self {
+        Self::from(u128::from(mask))
+    }
+}
+
+impl From<u128> for BitSet {
+    fn from(mask: u128) -> Self {
+        Self {
+            storage: Storage::Inline(mask),
+        }
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<usize> for BitSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_remove() {
+        let mut set = BitSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(3));
+        assert!(!set.insert(3));
+        assert!(set.insert(127));
+        assert_eq!(set.storage, Storage::Inline((1 << 127) | (1 << 3)));
+
+        assert!(set.insert(128));
+        assert!(matches!(set.storage, Storage::Heap(_)));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3, 127, 128]);
+        assert_eq!(set.len(), 3);
+
+        assert!(set.remove(128));
+        assert!(!set.remove(128));
+        assert_eq!(set.storage, Storage::Inline((1 << 127) | (1 << 3)));
+        assert!(set.remove(3));
+        assert!(set.remove(127));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_full() {
+        assert!(BitSet::full(0).is_empty());
+        assert_eq!(BitSet::full(3), BitSet::from(0b111_u64));
+        assert_eq!(BitSet::full(128), BitSet::from(u128::MAX));
+        let set = BitSet::full(300);
+        assert_eq!(set.len(), 300);
+        assert!(set.contains(299));
+        assert!(!set.contains(300));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: BitSet = [1, 2, 150, 300].into_iter().collect();
+        let b: BitSet = [2, 3, 300].into_iter().collect();
+        assert_eq!(a.union(&b), [1, 2, 3, 150, 300].into_iter().collect());
+        assert_eq!(a.intersection(&b), [2, 300].into_iter().collect());
+        assert_eq!(a.difference(&b), [1, 150].into_iter().collect());
+        assert_eq!(a.intersection(&b).difference(&a), BitSet::new());
+        assert!(a.intersection(&b).is_subset(&b));
+        assert!(!a.is_subset(&b));
+    }
+
+    #[test]
+    fn test_subsets() {
+        let set: BitSet = [0, 70, 140].into_iter().collect();
+        let subsets: Vec<_> = set.subsets().collect();
+        assert_eq!(subsets.len(), 8);
+        assert!(subsets.iter().all(|subset| subset.is_subset(&set)));
+        assert_eq!(subsets[7], set);
+        assert_eq!(set.k_subsets(2).count(), 3);
+        assert_eq!(BitSet::full(200).k_subsets(1).count(), 200);
+    }
+
+    #[test]
+    fn test_debug() {
+        let set: BitSet = [5, 1].into_iter().collect();
+        assert_eq!(format!("{set:?}"), "{1, 5}");
+    }
+}
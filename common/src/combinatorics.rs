@@ -0,0 +1,387 @@
+//! Iterators over subsets, permutations and combinations of `n` items.
+//!
+//! Items are identified by their index. Subsets come in two flavours: as bitmasks, where bit `i`
+//! is set if item `i` is chosen, and as sorted index vectors. Bitmasks are limited to 64 items.
+
+/// A mask with the lowest `k` bits set.
+fn low_bits(k: u32) -> u64 {
+    u64::MAX.checked_shr(u64::BITS - k).unwrap_or(0)
+}
+
+/// The mask following `previous` among the masks of `n` bits, see [`subset_masks`].
+fn next_mask(previous: u64, n: u32) -> Option<u64> {
+    let set_bits = previous.count_ones();
+    if set_bits >= n {
+        None
+    } else if previous == 0 {
+        Some(1)
+    } else if previous.trailing_zeros() == n - set_bits {
+        // we need to introduce one more set bit and start from the lsb side
+        Some(low_bits(set_bits + 1))
+    } else {
+        // we need to move the msb that can move up one position
+        let total_bits = u64::BITS;
+        let desired_zeros = total_bits - n;
+
+        // remove all leading zeros
+        let leading_zeros_front = previous.leading_zeros();
+        let next = previous << leading_zeros_front;
+        if leading_zeros_front > desired_zeros {
+            // the msb can move up one position
+
+            // remove the current msb
+            let next = (next << 1) >> 1;
+
+            // shift back to the original position
+            let next = next >> leading_zeros_front;
+
+            // add the new msb
+            let remaining_bits = total_bits - leading_zeros_front;
+            let next = next | (1 << remaining_bits);
+            Some(next)
+        } else {
+            // there are some leading ones we have to deal with first
+
+            // remove all leading ones
+            let leading_ones = next.leading_ones();
+            let next = next << leading_ones;
+
+            // remove leading zeros, there must be at least one
+            let leading_zeros = next.leading_zeros();
+            let next = next << leading_zeros;
+
+            // remove the current msb
+            let next = (next << 1) >> 1;
+
+            // shift back to the original position
+            let next = next >> leading_zeros;
+
+            // add the new msb
+            let remaining_bits = total_bits - leading_zeros;
+            let next = next | (1 << remaining_bits);
+
+            // add back old leading ones
+            let next = next >> leading_ones;
+            let old_msbs = low_bits(leading_ones);
+            let next = next | (old_msbs << (total_bits - leading_ones - leading_zeros + 1));
+
+            let next = next >> leading_zeros_front;
+            Some(next)
+        }
+    }
+}
+
+fn bit_count(n: usize) -> u32 {
+    u32::try_from(n)
+        .ok()
+        .filter(|&n| n <= u64::BITS)
+        .expect("bitmasks support at most 64 items")
+}
+
+/// All subsets of `n` items as bitmasks.
+///
+/// Subsets are ordered by size first. Subsets of the same size are ordered lexicographically by
+/// the indices of their items, which is the reverse colexicographical order of the masks. This is
+/// the sequence A359941 in the OEIS.
+///
+/// # Panics
+///
+/// Panics if `n` is larger than 64.
+///
+/// # Examples
+///
+/// ```
+/// use common::combinatorics::subset_masks;
+///
+/// let masks: Vec<_> = subset_masks(3).collect();
+/// assert_eq!(masks, [0b000, 0b001, 0b010, 0b100, 0b011, 0b101, 0b110, 0b111]);
+/// ```
+pub fn subset_masks(n: usize) -> impl Iterator<Item = u64> {
+    subset_masks_from(n, 0)
+}
+
+/// The subsets of [`subset_masks`] with at least `min_size` items.
+///
+/// # Panics
+///
+/// Panics if `n` is larger than 64.
+pub fn subset_masks_from(n: usize, min_size: usize) -> impl Iterator<Item = u64> {
+    let n = bit_count(n);
+    let mut current = u32::try_from(min_size)
+        .ok()
+        .filter(|&k| k <= n)
+        .map(low_bits);
+    std::iter::from_fn(move || {
+        current.inspect(|&previous| {
+            current = next_mask(previous, n);
+        })
+    })
+}
+
+/// All subsets of exactly `k` of `n` items as bitmasks, in the order of [`subset_masks`].
+///
+/// # Panics
+///
+/// Panics if `n` is larger than 64.
+///
+/// # Examples
+///
+/// ```
+/// use common::combinatorics::k_subset_masks;
+///
+/// let masks: Vec<_> = k_subset_masks(4, 2).collect();
+/// assert_eq!(masks, [0b0011, 0b0101, 0b1001, 0b0110, 0b1010, 0b1100]);
+/// ```
+pub fn k_subset_masks(n: usize, k: usize) -> impl Iterator<Item = u64> {
+    subset_masks_from(n, k).take_while(move |mask| mask.count_ones() as usize == k)
+}
+
+/// The indices of the items in a bitmask, in ascending order.
+pub fn mask_indices(mask: u64) -> impl Iterator<Item = usize> {
+    let mut remaining = mask;
+    std::iter::from_fn(move || {
+        let index = remaining.trailing_zeros();
+        (remaining != 0).then(|| {
+            remaining &= remaining - 1;
+            index as usize
+        })
+    })
+}
+
+/// The bitmask of the items with the given indices.
+///
+/// # Panics
+///
+/// Panics if an index is 64 or larger.
+#[must_use]
+pub fn indices_mask(indices: &[usize]) -> u64 {
+    indices.iter().fold(0, |mask, &i| {
+        assert!(i < u64::BITS as usize, "bitmasks support at most 64 items");
+        mask | (1 << i)
+    })
+}
+
+/// All subsets of exactly `k` of `n` items as sorted index vectors, in lexicographical order.
+///
+/// # Examples
+///
+/// ```
+/// use common::combinatorics::k_subsets;
+///
+/// let subsets: Vec<_> = k_subsets(4, 2).collect();
+/// assert_eq!(subsets, [[0, 1], [0, 2], [0, 3], [1, 2], [1, 3], [2, 3]]);
+/// ```
+pub fn k_subsets(n: usize, k: usize) -> impl Iterator<Item = Vec<usize>> {
+    let mut current = (k <= n).then(|| (0..k).collect::<Vec<_>>());
+    std::iter::from_fn(move || {
+        let subset = current.take()?;
+        // advance the last index that can still move up and pack the following ones behind it
+        if let Some(i) = (0..k).rev().find(|&i| subset[i] < n - k + i) {
+            let mut next = subset.clone();
+            let start = next[i] + 1;
+            for (offset, index) in next[i..].iter_mut().enumerate() {
+                *index = start + offset;
+            }
+            current = Some(next);
+        }
+        Some(subset)
+    })
+}
+
+/// All subsets of `n` items as sorted index vectors, ordered by size and then lexicographically.
+///
+/// This is the same order as [`subset_masks`].
+pub fn subsets(n: usize) -> impl Iterator<Item = Vec<usize>> {
+    (0..=n).flat_map(move |k| k_subsets(n, k))
+}
+
+/// All ordered selections of `k` distinct of `n` items, in lexicographical order.
+///
+/// # Examples
+///
+/// ```
+/// use common::combinatorics::k_permutations;
+///
+/// let permutations: Vec<_> = k_permutations(3, 2).collect();
+/// assert_eq!(permutations, [[0, 1], [0, 2], [1, 0], [1, 2], [2, 0], [2, 1]]);
+/// ```
+pub fn k_permutations(n: usize, k: usize) -> impl Iterator<Item = Vec<usize>> {
+    let mut current = (k <= n).then(|| (0..k).collect::<Vec<_>>());
+    let mut used: Vec<_> = (0..n).map(|i| i < k).collect();
+    std::iter::from_fn(move || {
+        let permutation = current.take()?;
+        let mut next = permutation.clone();
+        // release indices from the back until one can be replaced by a larger unused one
+        for i in (0..k).rev() {
+            used[next[i]] = false;
+            if let Some(larger) = (next[i] + 1..n).find(|&j| !used[j]) {
+                next[i] = larger;
+                used[larger] = true;
+                let mut unused = (0..n).filter(|&j| !used[j]);
+                for index in &mut next[i + 1..] {
+                    *index = unused.next().expect("enough unused items");
+                }
+                for &index in &next[i + 1..] {
+                    used[index] = true;
+                }
+                current = Some(next);
+                break;
+            }
+        }
+        Some(permutation)
+    })
+}
+
+/// All ways to choose `k` items from a multiset, in lexicographical order.
+///
+/// Item `i` can be chosen up to `multiplicities[i]` times. Each combination is a sorted index
+/// vector. With unlimited multiplicities these are the combinations with repetition.
+///
+/// # Examples
+///
+/// ```
+/// use common::combinatorics::multiset_combinations;
+///
+/// let combinations: Vec<_> = multiset_combinations(&[2, 1], 2).collect();
+/// assert_eq!(combinations, [[0, 0], [0, 1]]);
+/// ```
+pub fn multiset_combinations(
+    multiplicities: &[usize],
+    k: usize,
+) -> impl Iterator<Item = Vec<usize>> {
+    let multiplicities = multiplicities.to_vec();
+    let mut current = fill_smallest(&multiplicities, Vec::new(), 0, k);
+    std::iter::from_fn(move || {
+        let combination = current.take()?;
+        // replace a suffix by the smallest one that starts with a larger item
+        current = (0..k).rev().find_map(|i| {
+            let mut remaining = multiplicities.clone();
+            for &index in &combination[..i] {
+                remaining[index] -= 1;
+            }
+            fill_smallest(
+                &remaining,
+                combination[..i].to_vec(),
+                combination[i] + 1,
+                k - i,
+            )
+        });
+        Some(combination)
+    })
+}
+
+/// Appends the `count` smallest items from `start` on that are still available.
+fn fill_smallest(
+    available: &[usize],
+    mut prefix: Vec<usize>,
+    start: usize,
+    count: usize,
+) -> Option<Vec<usize>> {
+    let mut missing = count;
+    for (index, &available) in available.iter().enumerate().skip(start) {
+        let taken = available.min(missing);
+        prefix.extend(std::iter::repeat_n(index, taken));
+        missing -= taken;
+    }
+    (missing == 0).then_some(prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binomial(n: usize, k: usize) -> usize {
+        (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+    }
+
+    #[test]
+    fn test_subset_masks() {
+        // see https://oeis.org/A359941 for the expected values
+        assert_eq!(subset_masks(0).collect::<Vec<_>>(), [0]);
+        assert_eq!(subset_masks(1).collect::<Vec<_>>(), [0, 1]);
+        assert_eq!(subset_masks(2).collect::<Vec<_>>(), [0, 1, 2, 3]);
+        assert_eq!(
+            subset_masks(4).collect::<Vec<_>>(),
+            [0, 1, 2, 4, 8, 3, 5, 9, 6, 10, 12, 7, 11, 13, 14, 15]
+        );
+        assert_eq!(subset_masks(10).count(), 1 << 10);
+    }
+
+    #[test]
+    fn test_subset_masks_match_index_vectors() {
+        for n in 0..8 {
+            let masks: Vec<_> = subset_masks(n).collect();
+            let indices: Vec<_> = subsets(n).map(|subset| indices_mask(&subset)).collect();
+            assert_eq!(masks, indices);
+            for &mask in &masks {
+                let subset: Vec<_> = mask_indices(mask).collect();
+                assert_eq!(indices_mask(&subset), mask);
+            }
+        }
+    }
+
+    #[test]
+    fn test_k_subset_masks_wide() {
+        assert_eq!(k_subset_masks(64, 1).count(), 64);
+        assert_eq!(k_subset_masks(64, 2).count(), binomial(64, 2));
+        assert_eq!(k_subset_masks(64, 64).collect::<Vec<_>>(), [u64::MAX]);
+        assert_eq!(k_subset_masks(64, 63).last(), Some(u64::MAX - 1));
+        assert_eq!(k_subset_masks(3, 4).count(), 0);
+        assert_eq!(
+            subset_masks_from(5, 4).collect::<Vec<_>>(),
+            [15, 23, 27, 29, 30, 31]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 64 items")]
+    fn test_subset_masks_too_wide() {
+        let _ = subset_masks(65);
+    }
+
+    #[test]
+    fn test_k_subsets() {
+        for n in 0..7 {
+            for k in 0..=n + 1 {
+                let subsets: Vec<_> = k_subsets(n, k).collect();
+                assert_eq!(subsets.len(), if k <= n { binomial(n, k) } else { 0 });
+                assert!(subsets.windows(2).all(|w| w[0] < w[1]));
+                assert!(subsets.iter().all(|s| s.windows(2).all(|w| w[0] < w[1])));
+            }
+        }
+        assert_eq!(k_subsets(3, 0).collect::<Vec<_>>(), [Vec::<usize>::new()]);
+    }
+
+    #[test]
+    fn test_k_permutations() {
+        let permutations: Vec<_> = k_permutations(4, 4).collect();
+        assert_eq!(permutations.len(), 24);
+        assert_eq!(permutations.last(), Some(&vec![3, 2, 1, 0]));
+        assert!(permutations.windows(2).all(|w| w[0] < w[1]));
+
+        assert_eq!(k_permutations(5, 3).count(), 5 * 4 * 3);
+        assert_eq!(k_permutations(2, 3).count(), 0);
+        assert_eq!(k_permutations(0, 0).count(), 1);
+    }
+
+    #[test]
+    fn test_multiset_combinations() {
+        let combinations: Vec<_> = multiset_combinations(&[2, 0, 1, 3], 3).collect();
+        assert_eq!(
+            combinations,
+            [
+                [0, 0, 2],
+                [0, 0, 3],
+                [0, 2, 3],
+                [0, 3, 3],
+                [2, 3, 3],
+                [3, 3, 3]
+            ]
+        );
+
+        // combinations with repetition of 3 items taken 2 at a time
+        assert_eq!(multiset_combinations(&[2; 3], 2).count(), binomial(4, 2));
+        assert_eq!(multiset_combinations(&[1; 5], 2).count(), binomial(5, 2));
+        assert_eq!(multiset_combinations(&[1, 1], 3).count(), 0);
+    }
+}
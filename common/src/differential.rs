@@ -0,0 +1,149 @@
+//! Differential testing of two implementations that answer the same question.
+//!
+//! Both implementations run on generated inputs of increasing size. The first input on which
+//! they disagree is then shrunk by dropping lines for as long as they keep disagreeing, so that
+//! the reported input is as small as possible.
+
+use std::fmt::{Debug, Display};
+
+use crate::generate::Rng;
+use crate::solution::Solution;
+
+/// An input on which two implementations disagree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Disagreement<T> {
+    /// The size and seed of the generated input that first showed the disagreement.
+    pub size: usize,
+    pub seed: u64,
+    /// The input after shrinking.
+    pub input: String,
+    pub reference: T,
+    pub candidate: T,
+}
+
+impl<T: Debug> Display for Disagreement<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "expected {:?} but got {:?} (size {}, seed {}, shrunk) for:",
+            self.reference, self.candidate, self.size, self.seed
+        )?;
+        write!(f, "{}", self.input)
+    }
+}
+
+/// Runs two implementations on generated inputs and returns the smallest input on which they
+/// disagree, if any.
+///
+/// `sizes` are tried in order, so they should be increasing, with `seeds` inputs for each.
+/// Inputs that `parse` rejects, which can only happen while shrinking, are skipped.
+pub fn find<I, T: PartialEq>(
+    generate: impl Fn(&mut Rng, usize) -> String,
+    parse: impl Fn(&str) -> Option<I>,
+    sizes: impl IntoIterator<Item = usize>,
+    seeds: u64,
+    reference: impl Fn(&I) -> T,
+    candidate: impl Fn(&I) -> T,
+) -> Option<Disagreement<T>> {
+    let disagree = |input: &str| {
+        let input = parse(input)?;
+        let (reference, candidate) = (reference(&input), candidate(&input));
+        (reference != candidate).then_some((reference, candidate))
+    };
+
+    let (size, seed, mut input, mut answers) = sizes.into_iter().find_map(|size| {
+        (0..seeds).find_map(|seed| {
+            let input = generate(&mut Rng::new(seed), size);
+            disagree(&input).map(|answers| (size, seed, input, answers))
+        })
+    })?;
+
+    // drop single lines until no line can be dropped anymore
+    let mut line = 0;
+    while line < input.lines().count() {
+        let shrunk: String = input
+            .lines()
+            .enumerate()
+            .filter(|&(i, _)| i != line)
+            .map(|(_, text)| format!("{text}\n"))
+            .collect();
+        match disagree(&shrunk) {
+            Some(shrunk_answers) => {
+                input = shrunk;
+                answers = shrunk_answers;
+            }
+            None => line += 1,
+        }
+    }
+
+    let (reference, candidate) = answers;
+    Some(Disagreement {
+        size,
+        seed,
+        input,
+        reference,
+        candidate,
+    })
+}
+
+/// Runs two implementations on the generated inputs of a solution, see [`find`].
+///
+/// # Panics
+///
+/// Panics with the smallest input on which the implementations disagree.
+pub fn check<S: Solution, T: PartialEq + Debug>(
+    sizes: impl IntoIterator<Item = usize>,
+    seeds: u64,
+    reference: impl Fn(&S::Input) -> T,
+    candidate: impl Fn(&S::Input) -> T,
+) {
+    let generate = |rng: &mut Rng, size| {
+        S::generate(rng, size)
+            .unwrap_or_else(|| panic!("{} day {} has no input generator", S::YEAR, S::DAY))
+    };
+    let parse = |input: &str| S::parse(input).ok();
+    if let Some(disagreement) = find(generate, parse, sizes, seeds, reference, candidate) {
+        panic!("{disagreement}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{}\n", rng.range(0..=9)))
+            .collect()
+    }
+
+    fn parse(input: &str) -> Option<Vec<u32>> {
+        input.lines().map(|line| line.parse().ok()).collect()
+    }
+
+    #[test]
+    fn test_agreement() {
+        let sum = |numbers: &Vec<u32>| numbers.iter().sum::<u32>();
+        let doubled = |numbers: &Vec<u32>| numbers.iter().map(|n| 2 * n).sum::<u32>() / 2;
+        assert_eq!(find(numbers, parse, 0..20, 5, sum, doubled), None);
+    }
+
+    #[test]
+    fn test_smallest_disagreement() {
+        // wrong as soon as there is a 7 in the input
+        let max = |numbers: &Vec<u32>| numbers.iter().max().copied();
+        let broken = |numbers: &Vec<u32>| numbers.iter().filter(|&&n| n != 7).max().copied();
+
+        let disagreement = find(numbers, parse, 1..20, 10, max, broken).unwrap();
+        assert_eq!(disagreement.input, "7\n");
+        assert_eq!(disagreement.reference, Some(7));
+        assert_eq!(disagreement.candidate, None);
+        assert_eq!(
+            disagreement.to_string(),
+            format!(
+                "expected Some(7) but got None (size {}, seed {}, shrunk) for:\n7\n",
+                disagreement.size, disagreement.seed
+            )
+        );
+    }
+}
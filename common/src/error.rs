@@ -0,0 +1,326 @@
+use std::fmt::{Debug, Display};
+
+/// The result type used throughout the workspace.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// An error with optional context about where in the input it happened.
+///
+/// The location is given as file, line and column (both starting at 1) and the offending text is
+/// shown with a caret under the column when available. Several errors can be reported at once with
+/// [`Error::multiple`].
+///
+/// # Examples
+///
+/// ```
+/// use common::Error;
+///
+/// let error = Error::new("invalid light 'x'")
+///     .with_text("#.x#")
+///     .with_column(3)
+///     .with_line(7)
+///     .with_file("input.txt");
+/// assert_eq!(error.to_string(), "input.txt:7:3: invalid light 'x'\n    #.x#\n      ^");
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Error(Box<Details>);
+
+#[derive(Clone, PartialEq, Eq)]
+struct Details {
+    message: String,
+    file: Option<String>,
+    line: Option<usize>,
+    column: Option<usize>,
+    text: Option<String>,
+    errors: Vec<Error>,
+}
+
+impl Error {
+    #[must_use]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(Box::new(Details {
+            message: message.into(),
+            file: None,
+            line: None,
+            column: None,
+            text: None,
+            errors: Vec::new(),
+        }))
+    }
+
+    /// Combines several errors into one, e.g. all invalid lines of an input.
+    ///
+    /// A single error is returned as is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `errors` is empty.
+    #[must_use]
+    pub fn multiple(mut errors: Vec<Error>) -> Self {
+        assert!(!errors.is_empty(), "no errors to combine");
+        if errors.len() == 1 {
+            return errors.remove(0);
+        }
+        let mut error = Self::new(format!("{} errors", errors.len()));
+        error.0.errors = errors;
+        error
+    }
+
+    /// Converts the error of a `nom` parser that was run on `input`.
+    ///
+    /// The column is derived from the position at which the parser failed.
+    #[must_use]
+    pub fn from_nom(input: &str, error: &nom::Err<nom::error::Error<&str>>) -> Self {
+        let error = match error {
+            nom::Err::Error(error) | nom::Err::Failure(error) => error,
+            nom::Err::Incomplete(_) => return Self::new("incomplete input").with_text(input),
+        };
+
+        let message = if error.input.is_empty() {
+            "unexpected end of input".to_string()
+        } else {
+            format!("unexpected input ({})", error.code.description())
+        };
+        let offset = input.len().saturating_sub(error.input.len());
+        let column = input.get(..offset).map_or(offset, |s| s.chars().count()) + 1;
+        Self::new(message).with_text(input).with_column(column)
+    }
+
+    /// Sets the file the error (and all combined errors) occurred in.
+    #[must_use]
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        let file = file.into();
+        self.0.errors = std::mem::take(&mut self.0.errors)
+            .into_iter()
+            .map(|e| e.with_file(file.clone()))
+            .collect();
+        self.0.file = Some(file);
+        self
+    }
+
+    /// Sets the line (starting at 1) the error occurred on.
+    #[must_use]
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.0.line = Some(line);
+        self
+    }
+
+    /// Sets the column (starting at 1) the error occurred in.
+    #[must_use]
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.0.column = Some(column);
+        self
+    }
+
+    /// Sets the offending text, usually the whole line.
+    #[must_use]
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.0.text = Some(text.into());
+        self
+    }
+
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.0.message
+    }
+
+    #[must_use]
+    pub fn file(&self) -> Option<&str> {
+        self.0.file.as_deref()
+    }
+
+    #[must_use]
+    pub fn line(&self) -> Option<usize> {
+        self.0.line
+    }
+
+    #[must_use]
+    pub fn column(&self) -> Option<usize> {
+        self.0.column
+    }
+
+    #[must_use]
+    pub fn text(&self) -> Option<&str> {
+        self.0.text.as_deref()
+    }
+
+    /// The errors combined by [`Error::multiple`].
+    #[must_use]
+    pub fn errors(&self) -> &[Error] {
+        &self.0.errors
+    }
+}
+
+/// Runs a `nom` parser that has to consume the whole input.
+///
+/// # Errors
+///
+/// Returns an `Err` pointing at the failing column if the parser fails or does not consume the
+/// whole input.
+pub fn parse_complete<'a, T>(
+    input: &'a str,
+    parser: impl FnMut(&'a str) -> nom::IResult<&'a str, T>,
+) -> Result<T> {
+    nom::combinator::all_consuming(parser)(input)
+        .map(|(_, value)| value)
+        .map_err(|e| Error::from_nom(input, &e))
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut location = self.0.file.clone().unwrap_or_default();
+        match (self.0.line, self.0.column) {
+            (Some(line), column) => {
+                if !location.is_empty() {
+                    location.push(':');
+                }
+                location.push_str(&line.to_string());
+                if let Some(column) = column {
+                    location.push_str(&format!(":{column}"));
+                }
+            }
+            (None, Some(column)) => {
+                if !location.is_empty() {
+                    location.push(' ');
+                }
+                location.push_str(&format!("column {column}"));
+            }
+            (None, None) => {}
+        }
+        if !location.is_empty() {
+            write!(f, "{location}: ")?;
+        }
+        write!(f, "{}", self.0.message)?;
+
+        if let Some(text) = &self.0.text {
+            write!(f, "\n    {text}")?;
+            if let Some(column) = self.0.column {
+                write!(f, "\n    {}^", " ".repeat(column.saturating_sub(1)))?;
+            }
+        }
+        for error in &self.0.errors {
+            write!(f, "\n{error}")?;
+        }
+        Ok(())
+    }
+}
+
+// `main` prints errors with `Debug`, so it shows the same readable diagnostic as `Display`.
+impl Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::new(error.to_string())
+    }
+}
+
+impl From<std::num::ParseIntError> for Error {
+    fn from(error: std::num::ParseIntError) -> Self {
+        Self::new(format!("invalid number: {error}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Error::new("oops").to_string(), "oops");
+        assert_eq!(
+            Error::new("oops").with_file("input.txt").to_string(),
+            "input.txt: oops"
+        );
+        assert_eq!(
+            Error::new("oops").with_line(3).with_text("abc").to_string(),
+            "3: oops\n    abc"
+        );
+        assert_eq!(
+            Error::new("oops")
+                .with_column(1)
+                .with_text("abc")
+                .to_string(),
+            "column 1: oops\n    abc\n    ^"
+        );
+        assert_eq!(
+            Error::new("oops").with_line(3).with_column(2).to_string(),
+            "3:2: oops"
+        );
+        assert_eq!(format!("{:?}", Error::new("oops")), "oops");
+    }
+
+    #[test]
+    fn test_multiple() {
+        let error = Error::multiple(vec![Error::new("one")]);
+        assert_eq!(error, Error::new("one"));
+
+        let error = Error::multiple(vec![
+            Error::new("one")
+                .with_line(1)
+                .with_column(2)
+                .with_text("abc"),
+            Error::new("two").with_line(3),
+        ])
+        .with_file("input.txt");
+        assert_eq!(error.errors().len(), 2);
+        assert_eq!(
+            error.to_string(),
+            "input.txt: 2 errors\ninput.txt:1:2: one\n    abc\n     ^\ninput.txt:3: two"
+        );
+    }
+
+    #[test]
+    fn test_from_nom() {
+        let result = parse_complete("123 -> x", |input| {
+            nom::sequence::separated_pair(
+                nom::character::complete::u16,
+                nom::bytes::complete::tag(" -> "),
+                nom::character::complete::digit1,
+            )(input)
+        });
+        let error = result.unwrap_err();
+        assert_eq!(error.column(), Some(8));
+        assert_eq!(error.text(), Some("123 -> x"));
+        assert_eq!(
+            error.to_string(),
+            "column 8: unexpected input (Digit)\n    123 -> x\n           ^"
+        );
+    }
+
+    #[test]
+    fn test_parse_complete() {
+        let parser = nom::character::complete::u16::<&str, nom::error::Error<&str>>;
+        assert_eq!(parse_complete("123", parser).unwrap(), 123);
+
+        let error = parse_complete("123x", parser).unwrap_err();
+        assert_eq!(error.column(), Some(4));
+
+        let error = parse_complete("", parser).unwrap_err();
+        assert_eq!(error.message(), "unexpected end of input");
+    }
+
+    #[test]
+    fn test_from_nom_multibyte() {
+        let error = parse_complete("äö1", nom::character::complete::u16::<&str, _>).unwrap_err();
+        assert_eq!(error.column(), Some(1));
+        let error = parse_complete("1ä", nom::character::complete::u16::<&str, _>).unwrap_err();
+        assert_eq!(error.column(), Some(2));
+    }
+}
@@ -0,0 +1,332 @@
+//! Example tests declared in data files instead of inline asserts.
+//!
+//! A day keeps the examples of its puzzle description in an `examples.txt` next to its
+//! `Cargo.toml`. Each case starts with a `== <name>` line, followed by the expected answers as
+//! `part1: <answer>` and `part2: <answer>` lines (either may be left out), a `--` line and the
+//! input, which runs until the next case. Lines starting with `#` are comments outside of inputs.
+//!
+//! Some examples only show intermediate values, such as the signals on the wires of the day 7
+//! circuit. Those are given as `<key>: <value>` lines and checked by a function of the day that
+//! looks up the value of a key in the parsed input.
+//!
+//! ```text
+//! # 2015 day 1
+//! == balanced
+//! part1: 0
+//! --
+//! (())
+//! == basement
+//! part1: -1
+//! part2: 1
+//! --
+//! )
+//! ```
+//!
+//! The [`example_tests!`](crate::example_tests) macro then generates one test per case that runs
+//! it against the day's `Solution`.
+
+use crate::error::{Error, Result};
+use crate::solution::{DynSolution, Part, Registered, Solution};
+
+/// A single example: an input and the expected answers of some parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case {
+    pub name: String,
+    pub answers: Vec<(Part, String)>,
+    /// Expected values other than the answers, as keys and values.
+    pub values: Vec<(String, String)>,
+    /// The input with a trailing newline, like a real input file.
+    pub input: String,
+}
+
+/// Parses the cases of an examples file.
+///
+/// # Errors
+///
+/// Returns an `Err` if a header line is malformed, a case has no `--` line or expects neither
+/// answers nor values, or a name is used twice.
+pub fn parse(examples: &str) -> Result<Vec<Case>> {
+    let mut cases: Vec<Case> = Vec::new();
+    let mut in_input = false;
+    for (number, line) in examples.lines().enumerate() {
+        let invalid = |message: &str| Error::new(message).with_line(number + 1).with_text(line);
+
+        if let Some(name) = line.strip_prefix("== ") {
+            if let Some(case) = cases.last().filter(|_| !in_input) {
+                return Err(invalid(&format!("example '{}' has no input", case.name)));
+            }
+            let name = name.trim();
+            if cases.iter().any(|case| case.name == name) {
+                return Err(invalid("duplicate example name"));
+            }
+            cases.push(Case {
+                name: name.to_string(),
+                answers: Vec::new(),
+                values: Vec::new(),
+                input: String::new(),
+            });
+            in_input = false;
+            continue;
+        }
+
+        let Some(case) = cases.last_mut() else {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            return Err(invalid("expected '== <name>'"));
+        };
+        if in_input {
+            case.input.push_str(line);
+            case.input.push('\n');
+        } else if line == "--" {
+            if case.answers.is_empty() && case.values.is_empty() {
+                return Err(invalid("example without expected answers"));
+            }
+            in_input = true;
+        } else if !(line.trim().is_empty() || line.starts_with('#')) {
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| invalid("expected 'part<n>: <answer>', '<key>: <value>' or '--'"))?;
+            let value = value.trim().to_string();
+            if let Some(part) = key.strip_prefix("part") {
+                let part = part.parse().map_err(|_| invalid("invalid part"))?;
+                case.answers.push((part, value));
+            } else if key.trim().is_empty() {
+                return Err(invalid("missing key"));
+            } else {
+                case.values.push((key.trim().to_string(), value));
+            }
+        }
+    }
+
+    if let Some(case) = cases.last().filter(|_| !in_input) {
+        return Err(format!("example '{}' has no input", case.name).into());
+    }
+    for case in &mut cases {
+        let trimmed = case.input.trim_end_matches('\n').len();
+        case.input.truncate(trimmed);
+        case.input.push('\n');
+    }
+    Ok(cases)
+}
+
+/// Runs a single case of an examples file against a solution.
+///
+/// # Panics
+///
+/// Panics if the examples cannot be parsed, the case does not exist, any answer differs or the
+/// case expects values.
+pub fn check<S>(examples: &str, name: &str)
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    check_values::<S>(examples, name, |_, key| {
+        Err(Error::new(format!("no function to look up '{key}'")))
+    });
+}
+
+/// Runs a single case of an examples file against a solution, looking up the expected values
+/// with `value`.
+///
+/// # Panics
+///
+/// Panics if the examples cannot be parsed, the case does not exist or any answer or value
+/// differs.
+pub fn check_values<S>(
+    examples: &str,
+    name: &str,
+    value: impl Fn(&S::Input, &str) -> Result<String>,
+) where
+    S: Solution,
+    S::Input: 'static,
+{
+    let cases = parse(examples).unwrap_or_else(|e| panic!("invalid examples: {e}"));
+    let case = cases
+        .iter()
+        .find(|case| case.name == name)
+        .unwrap_or_else(|| panic!("no example named '{name}'"));
+
+    let solution = Registered::<S>::new();
+    let input = solution
+        .parse(&case.input)
+        .unwrap_or_else(|e| panic!("cannot parse example '{name}': {e}"));
+    for (part, expected) in &case.answers {
+        let answer = solution
+            .solve(input.as_ref(), *part)
+            .unwrap_or_else(|e| panic!("cannot solve part {part} of example '{name}': {e}"));
+        assert_eq!(&answer, expected, "part {part} of example '{name}'");
+    }
+
+    let input = input
+        .downcast_ref::<S::Input>()
+        .expect("the input was parsed by the same solution");
+    for (key, expected) in &case.values {
+        let actual = value(input, key)
+            .unwrap_or_else(|e| panic!("cannot look up '{key}' of example '{name}': {e}"));
+        assert_eq!(&actual, expected, "'{key}' of example '{name}'");
+    }
+}
+
+/// Checks that every case of an examples file has a test, and every test a case.
+///
+/// # Panics
+///
+/// Panics if the examples cannot be parsed or the names differ.
+pub fn check_names(examples: &str, names: &[&str]) {
+    let cases = parse(examples).unwrap_or_else(|e| panic!("invalid examples: {e}"));
+    let mut expected: Vec<_> = cases.iter().map(|case| case.name.as_str()).collect();
+    let mut names = names.to_vec();
+    expected.sort_unstable();
+    names.sort_unstable();
+    assert_eq!(names, expected, "the tests do not match the examples");
+}
+
+/// Generates a test for each named case of the crate's `examples.txt`, run against a `Solution`.
+///
+/// Case names have to be valid identifiers. An additional `all_examples_have_tests` test fails
+/// when the file and the listed names get out of sync.
+///
+/// The values of the examples are looked up with a function given after the solution, see
+/// [`check_values`].
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod examples {
+///     common::example_tests!(super::Day01: balanced, basement);
+///     // or with values
+///     common::example_tests!(super::Day07, super::signal: some_gates);
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty, $value:path: $($name:ident),+ $(,)?) => {
+        $crate::example_tests!(@tests $($name),+ => |examples, name| {
+            $crate::examples::check_values::<$solution>(examples, name, $value);
+        });
+    };
+    ($solution:ty: $($name:ident),+ $(,)?) => {
+        $crate::example_tests!(@tests $($name),+ => |examples, name| {
+            $crate::examples::check::<$solution>(examples, name);
+        });
+    };
+    (@tests $($name:ident),+ => $check:expr) => {
+        const EXAMPLES: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/examples.txt"));
+
+        $(
+            #[test]
+            fn $name() {
+                let check: fn(&str, &str) = $check;
+                check(EXAMPLES, stringify!($name));
+            }
+        )+
+
+        #[test]
+        fn all_examples_have_tests() {
+            $crate::examples::check_names(EXAMPLES, &[$(stringify!($name)),+]);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: &str = "# comment\n\n== first\npart1: 3\npart2: abc\n--\n1\n2\n\n== second\npart2: 5\nx: 7\n--\n# not a comment\n";
+
+    #[test]
+    fn test_parse() {
+        let cases = parse(EXAMPLES).unwrap();
+        assert_eq!(
+            cases,
+            [
+                Case {
+                    name: "first".to_string(),
+                    answers: vec![(Part::One, "3".to_string()), (Part::Two, "abc".to_string())],
+                    values: Vec::new(),
+                    input: "1\n2\n".to_string(),
+                },
+                Case {
+                    name: "second".to_string(),
+                    answers: vec![(Part::Two, "5".to_string())],
+                    values: vec![("x".to_string(), "7".to_string())],
+                    input: "# not a comment\n".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse("part1: 3\n--\nx").is_err());
+        assert!(parse("== a\n--\nx").is_err());
+        assert!(parse("== a\npart3: 1\n--\nx").is_err());
+        assert!(parse("== a\npart1 1\n--\nx").is_err());
+        assert!(parse("== a\n: 1\n--\nx").is_err());
+        assert!(parse("== a\npart1: 1\nx").is_err());
+        assert!(parse("== a\npart1: 1\n== b\npart1: 1\n--\nx").is_err());
+        assert!(parse("== a\npart1: 1\n--\nx\n== a\npart1: 1\n--\nx").is_err());
+    }
+
+    struct Count;
+
+    impl Solution for Count {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+
+        type Input = Vec<String>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Vec<String>> {
+            Ok(input.lines().map(str::to_string).collect())
+        }
+
+        fn part1(input: &Vec<String>) -> Result<usize> {
+            Ok(input.len())
+        }
+
+        fn part2(input: &Vec<String>) -> Result<String> {
+            Ok(input.concat())
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        check::<Count>("== a\npart1: 2\npart2: xy\n--\nx\ny\n", "a");
+    }
+
+    #[test]
+    #[should_panic(expected = "part 1 of example 'a'")]
+    fn test_check_mismatch() {
+        check::<Count>("== a\npart1: 3\n--\nx\ny\n", "a");
+    }
+
+    #[test]
+    fn test_check_values() {
+        check_values::<Count>("== a\nlast: y\n--\nx\ny\n", "a", |input, key| {
+            assert_eq!(key, "last");
+            Ok(input.last().cloned().unwrap_or_default())
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "'last' of example 'a'")]
+    fn test_check_values_mismatch() {
+        check_values::<Count>("== a\nlast: x\n--\nx\ny\n", "a", |input, _| {
+            Ok(input.last().cloned().unwrap_or_default())
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot look up 'last' of example 'a'")]
+    fn test_check_without_values() {
+        check::<Count>("== a\nlast: y\n--\nx\ny\n", "a");
+    }
+
+    #[test]
+    #[should_panic(expected = "the tests do not match the examples")]
+    fn test_check_names() {
+        check_names(EXAMPLES, &["first"]);
+    }
+}
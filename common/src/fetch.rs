@@ -0,0 +1,434 @@
+//! A client for the puzzle site that downloads inputs with a session cookie, caching them on disk.
+
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::{Error, Result};
+
+/// The environment variable holding the value of the `session` cookie of the puzzle site.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The environment variable overriding the base URL of the puzzle site.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The usual name of the file remembering the time of the last request, see
+/// [`Client::with_timestamp_file`].
+pub const TIMESTAMP_FILE: &str = ".last-request";
+
+/// The default minimum time between two requests.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = concat!("advent-of-code-runner/", env!("CARGO_PKG_VERSION"));
+
+/// When the puzzle of a day becomes available: midnight in the US Eastern time zone.
+#[must_use]
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    // days since the epoch of the given date in December, see
+    // https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = u64::from(year);
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let day_of_year = (153 * (12 - 3) + 2) / 5 + u64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+    UNIX_EPOCH + Duration::from_secs(days * 86_400 + 5 * 3_600)
+}
+
+/// Whether an input was read from the cache or downloaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(String),
+    Downloaded(String),
+}
+
+impl Fetched {
+    #[must_use]
+    pub fn into_string(self) -> String {
+        match self {
+            Self::Cached(input) | Self::Downloaded(input) => input,
+        }
+    }
+}
+
+/// A client for the puzzle site, authenticated with a session cookie.
+///
+/// Requests are spaced at least `min_interval` apart and inputs are only ever downloaded once.
+/// With a timestamp file the spacing also holds across clients, e.g. separate runs of the runner.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Cell<Option<SystemTime>>,
+    timestamp_file: Option<PathBuf>,
+}
+
+impl Client {
+    #[must_use]
+    pub fn new(session: impl Into<String>) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: session.into(),
+            min_interval: DEFAULT_MIN_INTERVAL,
+            last_request: Cell::new(None),
+            timestamp_file: None,
+        }
+    }
+
+    /// Creates a client from `AOC_SESSION` and the optional `AOC_BASE_URL`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if no session is set.
+    pub fn from_env() -> Result<Self> {
+        let session = std::env::var(SESSION_VAR)
+            .ok()
+            .filter(|session| !session.trim().is_empty())
+            .ok_or_else(|| format!("{SESSION_VAR} must be set to the session cookie"))?;
+        let client = Self::new(session.trim());
+        Ok(match std::env::var(BASE_URL_VAR) {
+            Ok(base_url) => client.with_base_url(base_url),
+            Err(_) => client,
+        })
+    }
+
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    #[must_use]
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Remembers the time of the last request in `path`, so that the minimum interval is kept by
+    /// all clients using the same file.
+    #[must_use]
+    pub fn with_timestamp_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.timestamp_file = Some(path.into());
+        self
+    }
+
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// The time of the last request recorded in the timestamp file, if there is a valid one.
+    fn read_timestamp(&self) -> Option<SystemTime> {
+        let contents = std::fs::read_to_string(self.timestamp_file.as_ref()?).ok()?;
+        let nanos = contents.trim().parse().ok()?;
+        Some(UNIX_EPOCH + Duration::from_nanos(nanos))
+    }
+
+    /// Waits until the minimum interval since the previous request has passed, by this client or
+    /// any other using the same timestamp file, and records the time of the new request.
+    fn throttle(&self) -> Result<()> {
+        let last = self.last_request.get().max(self.read_timestamp());
+        if let Some(last) = last {
+            let elapsed = SystemTime::now()
+                .duration_since(last)
+                .unwrap_or(Duration::ZERO);
+            if let Some(remaining) = self.min_interval.checked_sub(elapsed) {
+                std::thread::sleep(remaining);
+            }
+        }
+
+        let now = SystemTime::now();
+        self.last_request.set(Some(now));
+        if let Some(path) = &self.timestamp_file {
+            let nanos = now
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos();
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)
+                    .map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+            }
+            std::fs::write(path, format!("{nanos}\n"))
+                .map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+        }
+        Ok(())
+    }
+
+    /// Starts an authenticated request for a path relative to the base URL, once the rate limit
+    /// allows it.
+    fn request(&self, method: &str, path: &str) -> Result<ureq::Request> {
+        self.throttle()?;
+        Ok(self
+            .agent
+            .request(method, &format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session)))
+    }
+
+    fn read_response(
+        url: &str,
+        response: std::result::Result<ureq::Response, ureq::Error>,
+    ) -> Result<String> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| format!("cannot read response from {url}: {e}").into()),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                let reason = body.lines().next().unwrap_or_default().trim();
+                Err(format!("request to {url} failed with status {status}: {reason}").into())
+            }
+            Err(e) => Err(format!("request to {url} failed: {e}").into()),
+        }
+    }
+
+    /// Sends a `GET` request for a path relative to the base URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the request fails or is answered with an error status.
+    pub fn get(&self, path: &str) -> Result<String> {
+        let request = self.request("GET", path)?;
+        let url = request.url().to_string();
+        Self::read_response(&url, request.call())
+    }
+
+    /// Sends a `POST` request with a form body for a path relative to the base URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the request fails or is answered with an error status.
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let request = self.request("POST", path)?;
+        let url = request.url().to_string();
+        Self::read_response(&url, request.send_form(form))
+    }
+
+    /// Returns the input of a day, downloading it to `cache` unless it is already there.
+    ///
+    /// An empty file, such as the placeholder of a freshly created day, does not count as cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the puzzle is not unlocked yet, the download fails or the cache cannot
+    /// be read or written.
+    pub fn fetch_input(&self, year: u16, day: u8, cache: &Path) -> Result<Fetched> {
+        if cache.exists() {
+            let input = std::fs::read_to_string(cache)
+                .map_err(|e| format!("cannot read {}: {e}", cache.display()))?;
+            if !input.is_empty() {
+                return Ok(Fetched::Cached(input));
+            }
+        }
+        if !(1..=25).contains(&day) {
+            return Err(format!("invalid day: {day}").into());
+        }
+        if SystemTime::now() < unlock_time(year, day) {
+            return Err(format!("{year} day {day} is not unlocked yet").into());
+        }
+
+        let input = self.get(&format!("/{year}/day/{day}/input")).map_err(|e| {
+            Error::new(format!(
+                "cannot download the input of {year} day {day}: {e}"
+            ))
+        })?;
+        if let Some(dir) = cache.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+        }
+        std::fs::write(cache, &input)
+            .map_err(|e| format!("cannot write {}: {e}", cache.display()))?;
+        Ok(Fetched::Downloaded(input))
+    }
+}
+
+/// A minimal HTTP server answering requests with canned responses, for testing clients against.
+#[cfg(any(test, feature = "test-util"))]
+pub mod stub {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    /// A request as seen by the stub server.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub cookie: Option<String>,
+        pub body: String,
+    }
+
+    /// A running stub server with the requests it has seen so far.
+    pub struct Server {
+        pub url: String,
+        pub requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    /// Serves `respond(request)` as `(status, body)` on a local port until the process ends.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no local port can be bound or a request is malformed.
+    pub fn serve(respond: impl Fn(&Request) -> (u16, String) + Send + 'static) -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&requests);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut parts = line.split_whitespace();
+                let method = parts.next().unwrap_or_default().to_string();
+                let path = parts.next().unwrap_or_default().to_string();
+
+                let (mut cookie, mut length) = (None, 0);
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    let (name, value) = header.split_once(": ").unwrap_or((header, ""));
+                    match name.to_ascii_lowercase().as_str() {
+                        "cookie" => cookie = Some(value.to_string()),
+                        "content-length" => length = value.parse().unwrap(),
+                        _ => {}
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+
+                let request = Request {
+                    method,
+                    path,
+                    cookie,
+                    body: String::from_utf8(body).unwrap(),
+                };
+                let (status, body) = respond(&request);
+                seen.lock().unwrap().push(request);
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        Server { url, requests }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+
+    /// A fresh directory for the cache of a single test.
+    fn cache_dir(test: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{test}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        let seconds = |year, day| {
+            unlock_time(year, day)
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        };
+        // 2015-12-01T05:00:00Z and 2024-12-25T05:00:00Z
+        assert_eq!(seconds(2015, 1), 1_448_946_000);
+        assert_eq!(seconds(2024, 25), 1_735_102_800);
+    }
+
+    #[test]
+    fn test_fetch_input_downloads_once() {
+        let server = stub::serve(|request| (200, format!("input for {}\n", request.path)));
+        let client = Client::new("secret")
+            .with_base_url(format!("{}/", server.url))
+            .with_min_interval(Duration::ZERO);
+        let cache = cache_dir("once").join("2015").join("day_07.txt");
+        std::fs::create_dir_all(cache.parent().unwrap()).unwrap();
+        std::fs::write(&cache, "").unwrap();
+
+        let fetched = client.fetch_input(2015, 7, &cache).unwrap();
+        assert_eq!(
+            fetched,
+            Fetched::Downloaded("input for /2015/day/7/input\n".to_string())
+        );
+        assert_eq!(
+            std::fs::read_to_string(&cache).unwrap(),
+            "input for /2015/day/7/input\n"
+        );
+
+        let fetched = client.fetch_input(2015, 7, &cache).unwrap();
+        assert!(matches!(fetched, Fetched::Cached(_)));
+
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=secret"));
+    }
+
+    #[test]
+    fn test_fetch_input_errors() {
+        let server = stub::serve(|_| (400, "Puzzle inputs differ by user.\n".to_string()));
+        let client = Client::new("expired")
+            .with_base_url(server.url.clone())
+            .with_min_interval(Duration::ZERO);
+        let cache = cache_dir("errors").join("day_01.txt");
+
+        let error = client.fetch_input(2015, 1, &cache).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("status 400: Puzzle inputs differ by user."));
+        assert!(!cache.exists());
+
+        assert!(client.fetch_input(9999, 1, &cache).is_err());
+        assert!(client.fetch_input(2015, 26, &cache).is_err());
+        assert_eq!(server.requests.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let server = stub::serve(|_| (200, String::new()));
+        let client = Client::new("secret")
+            .with_base_url(server.url.clone())
+            .with_min_interval(Duration::from_millis(200));
+
+        let start = Instant::now();
+        client.get("/a").unwrap();
+        client.get("/b").unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_rate_limit_across_clients() {
+        let server = stub::serve(|_| (200, String::new()));
+        let timestamp = cache_dir("timestamp").join(".last-request");
+        let client = || {
+            Client::new("secret")
+                .with_base_url(server.url.clone())
+                .with_min_interval(Duration::from_millis(200))
+                .with_timestamp_file(&timestamp)
+        };
+
+        let start = Instant::now();
+        client().get("/a").unwrap();
+        assert!(timestamp.exists());
+        client().get("/b").unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert_eq!(server.requests.lock().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,227 @@
+//! Random puzzle inputs for stress tests and scaling benchmarks.
+//!
+//! A day generates inputs by implementing [`Solution::generate`](crate::solution::Solution),
+//! drawing from an [`Rng`] so that the same seed always yields the same input.
+
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+/// The lowercase ASCII letters, for `Rng::string`.
+pub const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// A small, seedable pseudo-random number generator (SplitMix64).
+///
+/// It is not suitable for anything but generating test data, but its output only depends on the
+/// seed, on every platform and across versions.
+///
+/// # Examples
+///
+/// ```
+/// use common::generate::Rng;
+///
+/// let mut rng = Rng::new(42);
+/// let roll = rng.range(1..=6);
+/// assert!((1..=6).contains(&roll));
+/// assert_eq!(Rng::new(42).range(1..=6), roll);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number below `bound`, with a negligible bias for bounds far below `u64::MAX`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is 0.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "empty range");
+        let wide = u128::from(self.next_u64()) * u128::from(bound);
+        u64::try_from(wide >> 64).expect("the high half fits")
+    }
+
+    /// A number in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    pub fn range<T: Uniform>(&mut self, range: RangeInclusive<T>) -> T {
+        T::sample(self, range)
+    }
+
+    /// An index into a collection of `len` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is 0.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..=len.checked_sub(1).expect("empty range"))
+    }
+
+    /// Returns `true` with a probability of `numerator / denominator`.
+    pub fn ratio(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    /// A random element of `items`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `items` is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// Shuffles `items` in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    /// A string of `len` characters taken from `alphabet`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alphabet` is empty.
+    pub fn string(&mut self, len: usize, alphabet: &[u8]) -> String {
+        (0..len)
+            .map(|_| char::from(*self.choose(alphabet)))
+            .collect()
+    }
+
+    /// `count` distinct, capitalized names made of letters only, like `Tibaro`.
+    pub fn names(&mut self, count: usize) -> Vec<String> {
+        const CONSONANTS: &[u8] = b"bdfgklmnprstvz";
+        const VOWELS: &[u8] = b"aeiou";
+
+        let mut seen = HashSet::new();
+        let mut names = Vec::with_capacity(count);
+        let mut collisions = 0;
+        while names.len() < count {
+            // longer names once short ones start to run out
+            let syllables = self.range(2..=3) + collisions / 8;
+            let mut name = String::new();
+            for _ in 0..syllables {
+                name.push(char::from(*self.choose(CONSONANTS)));
+                name.push(char::from(*self.choose(VOWELS)));
+            }
+            name[..1].make_ascii_uppercase();
+            if seen.insert(name.clone()) {
+                names.push(name);
+            } else {
+                collisions += 1;
+            }
+        }
+        names
+    }
+}
+
+/// An integer type that `Rng::range` can draw from.
+pub trait Uniform: Copy {
+    /// Draws a value from `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    fn sample(rng: &mut Rng, range: RangeInclusive<Self>) -> Self;
+}
+
+macro_rules! impl_uniform {
+    ($($t:ty),*) => {
+        $(impl Uniform for $t {
+            fn sample(rng: &mut Rng, range: RangeInclusive<Self>) -> Self {
+                let (low, high) = range.into_inner();
+                assert!(low <= high, "empty range");
+                let span = u64::try_from(i128::from(high) - i128::from(low))
+                    .ok()
+                    .and_then(|span| span.checked_add(1));
+                let offset = match span {
+                    Some(span) => rng.below(span),
+                    None => rng.next_u64(),
+                };
+                Self::try_from(i128::from(low) + i128::from(offset)).expect("within the range")
+            }
+        })*
+    };
+}
+
+impl_uniform!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl Uniform for usize {
+    fn sample(rng: &mut Rng, range: RangeInclusive<Self>) -> Self {
+        let (low, high) = range.into_inner();
+        let widen = |n: usize| u64::try_from(n).expect("usize fits in u64");
+        Self::try_from(rng.range(widen(low)..=widen(high))).expect("within the range")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic() {
+        let draw = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..8).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(7), draw(8));
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(1);
+        let mut seen = HashSet::new();
+        for _ in 0..1000 {
+            let value = rng.range(-2..=2);
+            assert!((-2..=2).contains(&value));
+            seen.insert(value);
+        }
+        assert_eq!(seen.len(), 5);
+
+        assert_eq!(rng.range(3_u8..=3), 3);
+        rng.range(0..=u64::MAX);
+        rng.range(i64::MIN..=i64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn test_empty_range() {
+        Rng::new(0).index(0);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut items: Vec<_> = (0..20).collect();
+        Rng::new(3).shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_names() {
+        let names = Rng::new(5).names(500);
+        assert_eq!(names.iter().collect::<HashSet<_>>().len(), 500);
+        assert!(names.iter().all(|name| {
+            name.starts_with(|c: char| c.is_ascii_uppercase())
+                && name.chars().all(|c| c.is_ascii_alphabetic())
+        }));
+    }
+}
@@ -0,0 +1,617 @@
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+/// Integer types that can be moved by a signed offset.
+pub trait Coordinate: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Adds a signed offset, returning `None` on overflow.
+    fn checked_offset(self, delta: isize) -> Option<Self>;
+}
+
+macro_rules! impl_signed_coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_offset(self, delta: isize) -> Option<Self> {
+                self.checked_add(Self::try_from(delta).ok()?)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_unsigned_coordinate {
+    ($($t:ty => $s:ty),*) => {
+        $(impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_offset(self, delta: isize) -> Option<Self> {
+                self.checked_add_signed(<$s>::try_from(delta).ok()?)
+            }
+        })*
+    };
+}
+
+impl_signed_coordinate!(i8, i16, i32, i64, i128, isize);
+impl_unsigned_coordinate!(u8 => i8, u16 => i16, u32 => i32, u64 => i64, u128 => i128, usize => isize);
+
+/// A point on a 2D plane.
+///
+/// Points use screen coordinates like the positions of a [`Grid`](crate::Grid): the y axis points
+/// south, so [`Dir4::North`] decreases `y`.
+///
+/// # Examples
+///
+/// ```
+/// use common::geometry::{Dir4, Point2};
+///
+/// let mut point = Point2::new(0, 0);
+/// point += Dir4::North;
+/// point += Dir4::East;
+/// assert_eq!(point, Point2::new(1, -1));
+/// assert_eq!(point.manhattan(Point2::new(-2, 0)), 4);
+/// assert_eq!("3,4".parse(), Ok(Point2::new(3, 4)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coordinate> Point2<T> {
+    /// Moves the point by a signed offset, returning `None` on overflow.
+    #[must_use]
+    pub fn checked_offset(self, (dx, dy): (isize, isize)) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_offset(dx)?,
+            self.y.checked_offset(dy)?,
+        ))
+    }
+
+    /// Moves the point one step in a direction, returning `None` on overflow.
+    #[must_use]
+    pub fn checked_step(self, direction: impl Direction) -> Option<Self> {
+        self.checked_offset(direction.delta())
+    }
+
+    /// Moves the point one step in a direction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinates overflow.
+    #[must_use]
+    pub fn step(self, direction: impl Direction) -> Self {
+        self.checked_step(direction)
+            .expect("point moved out of the coordinate range")
+    }
+
+    /// The neighbours in all given directions, skipping those that overflow.
+    pub fn neighbors<'a, D: Direction>(self, directions: &'a [D]) -> impl Iterator<Item = Self> + 'a
+    where
+        T: 'a,
+    {
+        directions.iter().filter_map(move |&d| self.checked_step(d))
+    }
+
+    /// The taxicab distance, the sum of the distances along both axes.
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// The chessboard distance, the larger of the distances along both axes.
+    #[must_use]
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+}
+
+fn abs_diff<T: Coordinate>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Coordinate> Add<Dir4> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, direction: Dir4) -> Self {
+        self.step(direction)
+    }
+}
+
+impl<T: Coordinate> AddAssign<Dir4> for Point2<T> {
+    fn add_assign(&mut self, direction: Dir4) {
+        *self = self.step(direction);
+    }
+}
+
+impl<T: Coordinate> Add<Dir8> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, direction: Dir8) -> Self {
+        self.step(direction)
+    }
+}
+
+impl<T: Coordinate> AddAssign<Dir8> for Point2<T> {
+    fn add_assign(&mut self, direction: Dir8) {
+        *self = self.step(direction);
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: FromStr> FromStr for Point2<T> {
+    type Err = Error;
+
+    /// Parses a point written as `x,y`.
+    fn from_str(input: &str) -> Result<Self> {
+        let (x, y) = input
+            .split_once(',')
+            .ok_or_else(|| Error::new("invalid point, expected 'x,y'").with_text(input))?;
+        let coordinate = |value: &str, column: usize| {
+            value.parse().map_err(|_| {
+                Error::new(format!("invalid coordinate '{value}'"))
+                    .with_column(column)
+                    .with_text(input)
+            })
+        };
+        Ok(Self::new(
+            coordinate(x, 1)?,
+            coordinate(y, x.chars().count() + 2)?,
+        ))
+    }
+}
+
+impl<T: FromStr> TryFrom<&str> for Point2<T> {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        input.parse()
+    }
+}
+
+/// A direction that moves a point by a fixed offset.
+pub trait Direction: Copy {
+    /// The offset of one step as `(dx, dy)`.
+    fn delta(self) -> (isize, isize);
+}
+
+/// The four orthogonal directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    /// All directions, clockwise starting at north.
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// Turns 90° clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Turns 90° counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Maps `^`, `>`, `v` and `<` to directions.
+    #[must_use]
+    pub fn arrows() -> CharMap<Self> {
+        CharMap::new([
+            ('^', Self::North),
+            ('>', Self::East),
+            ('v', Self::South),
+            ('<', Self::West),
+        ])
+    }
+
+    /// Maps `N`, `E`, `S` and `W` to directions.
+    #[must_use]
+    pub fn compass() -> CharMap<Self> {
+        CharMap::new([
+            ('N', Self::North),
+            ('E', Self::East),
+            ('S', Self::South),
+            ('W', Self::West),
+        ])
+    }
+
+    /// Maps `U`, `R`, `D` and `L` to directions.
+    #[must_use]
+    pub fn letters() -> CharMap<Self> {
+        CharMap::new([
+            ('U', Self::North),
+            ('R', Self::East),
+            ('D', Self::South),
+            ('L', Self::West),
+        ])
+    }
+}
+
+impl Direction for Dir4 {
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Self::North => (0, -1),
+            Self::East => (1, 0),
+            Self::South => (0, 1),
+            Self::West => (-1, 0),
+        }
+    }
+}
+
+/// Parses one of `^`, `>`, `v` and `<`.
+impl TryFrom<char> for Dir4 {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        Self::arrows().parse(c)
+    }
+}
+
+/// The four orthogonal and four diagonal directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    /// All directions, clockwise starting at north.
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// Turns 45° clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns 45° counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl Direction for Dir8 {
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(direction: Dir4) -> Self {
+        match direction {
+            Dir4::North => Self::North,
+            Dir4::East => Self::East,
+            Dir4::South => Self::South,
+            Dir4::West => Self::West,
+        }
+    }
+}
+
+/// A configurable mapping between characters and values such as directions.
+///
+/// # Examples
+///
+/// ```
+/// use common::geometry::{CharMap, Dir4};
+///
+/// let map = CharMap::new([('u', Dir4::North), ('d', Dir4::South)]);
+/// assert_eq!(map.parse_str("ud").unwrap(), [Dir4::North, Dir4::South]);
+/// assert_eq!(map.to_char(Dir4::South), Some('d'));
+/// assert_eq!(map.parse_str("ux").unwrap_err().column(), Some(2));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CharMap<D> {
+    entries: Vec<(char, D)>,
+}
+
+impl<D: Copy + PartialEq + std::fmt::Debug> CharMap<D> {
+    #[must_use]
+    pub fn new(entries: impl IntoIterator<Item = (char, D)>) -> Self {
+        Self {
+            entries: entries.into_iter().collect(),
+        }
+    }
+
+    /// Looks up the value of a character.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the character is not mapped.
+    pub fn parse(&self, c: char) -> Result<D> {
+        self.entries
+            .iter()
+            .find(|&&(key, _)| key == c)
+            .map(|&(_, value)| value)
+            .ok_or_else(|| Error::new(format!("unmapped character '{c}'")))
+    }
+
+    /// Looks up the values of all characters of a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` pointing at the first character that is not mapped.
+    pub fn parse_str(&self, input: &str) -> Result<Vec<D>> {
+        input
+            .chars()
+            .enumerate()
+            .map(|(i, c)| self.parse(c).map_err(|e| e.with_column(i + 1)))
+            .collect()
+    }
+
+    /// Looks up the character of a value.
+    #[must_use]
+    pub fn to_char(&self, value: D) -> Option<char> {
+        self.entries
+            .iter()
+            .find(|&&(_, v)| v == value)
+            .map(|&(key, _)| key)
+    }
+}
+
+/// The smallest axis-aligned rectangle containing a set of points, including its edges.
+///
+/// # Examples
+///
+/// ```
+/// use common::geometry::{BoundingBox, Point2};
+///
+/// let points = [Point2::new(1, 5), Point2::new(-2, 3), Point2::new(0, 7)];
+/// let bounds = BoundingBox::from_points(points).unwrap();
+/// assert_eq!(bounds.min, Point2::new(-2, 3));
+/// assert_eq!(bounds.max, Point2::new(1, 7));
+/// assert_eq!((bounds.width(), bounds.height()), (4, 5));
+/// assert!(bounds.contains(Point2::new(0, 4)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BoundingBox<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+impl<T: Coordinate> BoundingBox<T> {
+    /// The bounding box of a single point.
+    #[must_use]
+    pub fn new(point: Point2<T>) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// The bounding box of all points, or `None` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = Point2<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = Self::new(points.next()?);
+        Some(points.fold(first, |bounds, point| bounds.include(point)))
+    }
+
+    /// Grows the bounding box to contain `point`.
+    #[must_use]
+    pub fn include(self, point: Point2<T>) -> Self {
+        Self {
+            min: Point2::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+            max: Point2::new(self.max.x.max(point.x), self.max.y.max(point.y)),
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: Point2<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    #[must_use]
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    #[must_use]
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let point = Point2::new(0_i64, 0);
+        assert_eq!(point + Dir4::North, Point2::new(0, -1));
+        assert_eq!(point + Dir4::West, Point2::new(-1, 0));
+        assert_eq!(point + Dir8::SouthEast, Point2::new(1, 1));
+
+        let point = Point2::new(0_usize, 0);
+        assert_eq!(point.checked_step(Dir4::North), None);
+        assert_eq!(point.checked_step(Dir8::SouthEast), Some(Point2::new(1, 1)));
+        assert_eq!(point.neighbors(&Dir8::ALL).count(), 3);
+        assert_eq!(Point2::new(1_u8, 1).neighbors(&Dir4::ALL).count(), 4);
+        assert_eq!(Point2::new(255_u8, 0).checked_step(Dir4::East), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of the coordinate range")]
+    fn test_step_overflow() {
+        let _ = Point2::new(0_u32, 0) + Dir4::West;
+    }
+
+    #[test]
+    fn test_turning() {
+        assert_eq!(Dir4::North.turn_right(), Dir4::East);
+        assert_eq!(Dir4::North.turn_left(), Dir4::West);
+        assert_eq!(Dir4::East.reverse(), Dir4::West);
+        assert_eq!(Dir8::North.turn_right(), Dir8::NorthEast);
+        assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+        assert_eq!(Dir8::SouthWest.reverse(), Dir8::NorthEast);
+        for direction in Dir4::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(Dir8::from(direction).delta(), direction.delta());
+        }
+        for direction in Dir8::ALL {
+            let (dx, dy) = direction.delta();
+            assert_eq!(direction.reverse().delta(), (-dx, -dy));
+        }
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 5);
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(a.chebyshev(b), 7);
+        assert_eq!(Point2::new(3_usize, 0).manhattan(Point2::new(1, 2)), 4);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut point = Point2::new(1, 2) + Point2::new(3, 4);
+        assert_eq!(point, Point2::new(4, 6));
+        point -= Point2::new(1, 1);
+        assert_eq!(point - Point2::new(3, 5), Point2::new(0, 0));
+        assert_eq!(<(i32, i32)>::from(point), (3, 5));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Point2::try_from("123,456"), Ok(Point2::new(123_usize, 456)));
+        assert_eq!(Point2::new(-1, 2).to_string(), "-1,2");
+        assert!("12".parse::<Point2<u32>>().is_err());
+
+        let error = "12,x".parse::<Point2<u32>>().unwrap_err();
+        assert_eq!(error.column(), Some(4));
+    }
+
+    #[test]
+    fn test_char_maps() {
+        assert_eq!(Dir4::try_from('v'), Ok(Dir4::South));
+        assert_eq!(
+            Dir4::try_from('x').unwrap_err().to_string(),
+            "unmapped character 'x'"
+        );
+        assert_eq!(
+            Dir4::compass().parse_str("NESW").unwrap(),
+            Dir4::ALL.to_vec()
+        );
+        assert_eq!(Dir4::letters().parse('L'), Ok(Dir4::West));
+        assert_eq!(Dir4::arrows().to_char(Dir4::East), Some('>'));
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        assert_eq!(BoundingBox::<i32>::from_points([]), None);
+        let bounds = BoundingBox::new(Point2::new(2_usize, 3)).include(Point2::new(0, 4));
+        assert_eq!(bounds.min, Point2::new(0, 3));
+        assert_eq!(bounds.max, Point2::new(2, 4));
+        assert_eq!((bounds.width(), bounds.height()), (3, 2));
+        assert!(!bounds.contains(Point2::new(3, 3)));
+    }
+}
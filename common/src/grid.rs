@@ -0,0 +1,536 @@
+use std::ops::{Bound, Index, IndexMut, Range, RangeBounds};
+
+use crate::error::{Error, Result};
+use crate::geometry::{Dir4, Dir8, Direction};
+
+/// A position in a grid as `(x, y)`, with `(0, 0)` in the top left corner.
+pub type Position = (usize, usize);
+
+/// A rectangular 2D grid stored row by row in a single `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// use common::Grid;
+///
+/// let mut grid = Grid::from_map("#..\n.#.").unwrap();
+/// assert_eq!((grid.width(), grid.height()), (3, 2));
+/// assert!(grid[(1, 1)]);
+///
+/// grid[(2, 0)] = true;
+/// assert_eq!(grid.neighbors8((1, 0)).filter(|&p| grid[p]).count(), 3);
+/// assert_eq!(grid.to_map(), "#.#\n.#.\n");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawGrid<T>"))]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// A deserialized grid whose size has not been checked against its cells yet.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<RawGrid<T>> for Grid<T> {
+    type Error = Error;
+
+    fn try_from(raw: RawGrid<T>) -> Result<Self> {
+        Self::from_vec(raw.width, raw.height, raw.cells)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with all cells set to `value`.
+    #[must_use]
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid by calling `f` for every position.
+    #[must_use]
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid from its cells given row by row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the number of cells does not match the size.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() != width * height {
+            return Err(Error::new(format!(
+                "expected {} cells for a {width}x{height} grid, found {}",
+                width * height,
+                cells.len()
+            )));
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses a grid from lines of characters, converting each character with `f`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` pointing at the first character `f` rejects or at the first line whose
+    /// length differs from the first line.
+    pub fn from_lines<E: Into<Error>>(
+        lines: impl Iterator<Item = impl AsRef<str>>,
+        mut f: impl FnMut(char) -> std::result::Result<T, E>,
+    ) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (y, line) in lines.enumerate() {
+            let line = line.as_ref();
+            let at = |e: Error, x: usize| e.with_line(y + 1).with_column(x + 1).with_text(line);
+
+            let before = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                cells.push(f(c).map_err(|e| at(e.into(), x))?);
+            }
+            let length = cells.len() - before;
+            match width {
+                None => width = Some(length),
+                Some(width) if width != length => {
+                    let message = format!("expected {width} cells, found {length}");
+                    return Err(at(Error::new(message), length.min(width)));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Self::from_vec(width.unwrap_or(0), height, cells)
+    }
+
+    /// Parses a grid from a character map, converting each character with `f`.
+    ///
+    /// # Errors
+    ///
+    /// See [`Grid::from_lines`].
+    pub fn parse<E: Into<Error>>(
+        input: &str,
+        f: impl FnMut(char) -> std::result::Result<T, E>,
+    ) -> Result<Self> {
+        Self::from_lines(input.lines(), f)
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    #[must_use]
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.1 * self.width + position.0])
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.1 * self.width + position.0])
+    }
+
+    /// Gets a cell, wrapping around the edges in both directions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty.
+    #[must_use]
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        &self[self.wrap(x, y)]
+    }
+
+    /// Gets a cell mutably, wrapping around the edges in both directions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty.
+    pub fn get_wrapping_mut(&mut self, x: isize, y: isize) -> &mut T {
+        let position = self.wrap(x, y);
+        &mut self[position]
+    }
+
+    fn wrap(&self, x: isize, y: isize) -> Position {
+        assert!(!self.cells.is_empty(), "cannot wrap around an empty grid");
+        let wrap = |value: isize, size: usize| {
+            let size = isize::try_from(size).expect("grid size fits into isize");
+            usize::try_from(value.rem_euclid(size)).expect("remainder is not negative")
+        };
+        (wrap(x, self.width), wrap(y, self.height))
+    }
+
+    /// Moves a position by an offset, returning `None` if it leaves the grid.
+    #[must_use]
+    pub fn offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(position).then_some(position)
+    }
+
+    /// Moves a position one step in a direction, returning `None` if it leaves the grid.
+    ///
+    /// Rows grow downwards, so [`Dir4::North`] moves to the previous row.
+    #[must_use]
+    pub fn step(&self, position: Position, direction: impl Direction) -> Option<Position> {
+        self.offset(position, direction.delta())
+    }
+
+    /// The orthogonal neighbours of a position that are inside the grid, clockwise starting at
+    /// north.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The orthogonal and diagonal neighbours of a position that are inside the grid, clockwise
+    /// starting at north.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// All cells mutably with their positions, row by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    /// All cells, row by row.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    #[must_use]
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// The cells of a column from top to bottom, empty if `x` is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The cells of a rectangular region with their positions, clamped to the grid.
+    pub fn region(
+        &self,
+        x: impl RangeBounds<usize>,
+        y: impl RangeBounds<usize>,
+    ) -> impl Iterator<Item = (Position, &T)> {
+        let xs = clamp(&x, self.width);
+        let ys = clamp(&y, self.height);
+        ys.flat_map(move |y| xs.clone().map(move |x| (x, y)))
+            .map(|position| (position, &self[position]))
+    }
+
+    /// The cells of a rectangular region mutably with their positions, clamped to the grid.
+    pub fn region_mut(
+        &mut self,
+        x: impl RangeBounds<usize>,
+        y: impl RangeBounds<usize>,
+    ) -> impl Iterator<Item = (Position, &mut T)> {
+        let xs = clamp(&x, self.width);
+        let ys = clamp(&y, self.height);
+        let width = self.width;
+        self.cells
+            .chunks_mut(width.max(1))
+            .enumerate()
+            .skip(ys.start)
+            .take(ys.len())
+            .flat_map(move |(y, row)| {
+                row[xs.clone()]
+                    .iter_mut()
+                    .zip(xs.clone())
+                    .map(move |(cell, x)| ((x, y), cell))
+            })
+    }
+
+    /// Creates a grid of the same size by converting every cell.
+    #[must_use]
+    pub fn map<U>(&self, mut f: impl FnMut(Position, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self
+                .iter()
+                .map(|(position, cell)| f(position, cell))
+                .collect(),
+        }
+    }
+
+    /// Renders the grid as text with one line per row, converting each cell with `f`.
+    #[must_use]
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            text.extend(row.iter().map(&mut f));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl Grid<bool> {
+    /// Parses a map of `#` (`true`) and `.` (`false`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` pointing at the first other character or at a line of different length.
+    pub fn from_map(input: &str) -> Result<Self> {
+        Self::parse(input, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(format!("invalid cell '{c}', expected '#' or '.'")),
+        })
+    }
+
+    /// Renders the grid as a map of `#` (`true`) and `.` (`false`).
+    #[must_use]
+    pub fn to_map(&self) -> String {
+        self.render(|&cell| if cell { '#' } else { '.' })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "position {position:?} outside of {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {position:?} outside of {width}x{height} grid"))
+    }
+}
+
+/// Converts range bounds into a range within `0..size`.
+fn clamp(range: &impl RangeBounds<usize>, size: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => size,
+    };
+    let end = end.min(size);
+    start.min(end)..end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#..\n.#.\n..#\n#..\n";
+
+    #[test]
+    fn test_from_map() {
+        let grid = Grid::from_map(MAP).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid.get((0, 0)), Some(&true));
+        assert_eq!(grid.get((1, 0)), Some(&false));
+        assert_eq!(grid.get((0, 3)), Some(&true));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 4)), None);
+        assert_eq!(grid.to_map(), MAP);
+    }
+
+    #[test]
+    fn test_from_map_invalid() {
+        let error = Grid::from_map("#.\n#x").unwrap_err();
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.column(), Some(2));
+
+        let error = Grid::from_map("#.\n#").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "2:2: expected 2 cells, found 1\n    #\n     ^"
+        );
+    }
+
+    #[test]
+    fn test_from_map_empty() {
+        let grid = Grid::from_map("").unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.positions().count(), 0);
+        assert_eq!(grid.to_map(), "");
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let grid = Grid::from_vec(2, 2, vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(grid[(1, 0)], 2);
+        assert_eq!(grid[(0, 1)], 3);
+        assert!(Grid::from_vec(2, 2, vec![1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let grid = Grid::from_fn(3, 2, |(x, y)| x + 10 * y);
+        assert_eq!(
+            grid.values().copied().collect::<Vec<_>>(),
+            [0, 1, 2, 10, 11, 12]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of 3x4 grid")]
+    fn test_index_outside() {
+        let grid = Grid::from_map(MAP).unwrap();
+        let _ = grid[(3, 0)];
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let mut grid = Grid::from_fn(3, 2, |(x, y)| x + 10 * y);
+        assert_eq!(*grid.get_wrapping(-1, 0), 2);
+        assert_eq!(*grid.get_wrapping(3, 3), 10);
+        assert_eq!(*grid.get_wrapping(-4, -3), 12);
+
+        *grid.get_wrapping_mut(5, -1) = 42;
+        assert_eq!(grid[(2, 1)], 42);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8((2, 1)).count(), 5);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), (2, 1)), Some((2, 1)));
+        assert_eq!(grid.step((1, 1), Dir4::North), Some((1, 0)));
+        assert_eq!(grid.step((1, 1), Dir8::SouthWest), Some((0, 2)));
+        assert_eq!(grid.step((0, 0), Dir8::NorthWest), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::from_fn(3, 2, |(x, y)| x + 10 * y);
+        assert_eq!(grid.row(1), Some(&[10, 11, 12][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [1, 11]);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.sum::<usize>())
+                .collect::<Vec<_>>(),
+            [10, 12, 14]
+        );
+    }
+
+    #[test]
+    fn test_region() {
+        let mut grid = Grid::from_fn(4, 4, |(x, y)| x + 10 * y);
+        let region: Vec<_> = grid.region(1..=2, 2..).map(|(_, &v)| v).collect();
+        assert_eq!(region, [21, 22, 31, 32]);
+        assert_eq!(grid.region(3..10, ..1).count(), 1);
+        assert_eq!(grid.region(5.., ..).count(), 0);
+
+        grid.region_mut(..2, 1..=1).for_each(|(_, v)| *v = 0);
+        assert_eq!(grid.row(1), Some(&[0, 0, 12, 13][..]));
+
+        let positions: Vec<_> = grid.region_mut(2..3, 2..4).map(|(p, _)| p).collect();
+        assert_eq!(positions, [(2, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn test_map_and_render() {
+        let grid = Grid::from_map(MAP).unwrap();
+        let counts = grid.map(|position, _| grid.neighbors8(position).filter(|&p| grid[p]).count());
+        let digit = |&n: &usize| char::from_digit(u32::try_from(n).unwrap(), 10).unwrap();
+        assert_eq!(counts.render(digit), "121\n222\n231\n021\n");
+        assert_eq!(grid.iter().filter(|(_, &cell)| cell).count(), 4);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let grid = Grid::from_fn(2, 2, |(x, y)| x + 10 * y);
+        let json = serde_json::to_string(&grid).unwrap();
+        assert_eq!(json, r#"{"width":2,"height":2,"cells":[0,1,10,11]}"#);
+        assert_eq!(serde_json::from_str::<Grid<usize>>(&json).unwrap(), grid);
+        assert!(
+            serde_json::from_str::<Grid<usize>>(r#"{"width":2,"height":2,"cells":[0]}"#).is_err()
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::solution::Result;
+
+/// The environment variable pointing to a directory that holds the inputs as
+/// `<year>/day_<dd>.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The command line option selecting an explicit input (`-` for stdin).
+pub const INPUT_ARG: &str = "--input";
+
+/// Where the puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Reads the whole input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input cannot be read.
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Self::File(path) => std::fs::read_to_string(path)
+                .map_err(|e| format!("cannot read {}: {e}", path.display()).into()),
+            Self::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("cannot read stdin: {e}"))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// Removes `<name> <value>` or `<name>=<value>` from the arguments and returns the value.
+///
+/// # Errors
+///
+/// Returns an `Err` if the option is missing its value or given more than once.
+pub fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>> {
+    let mut option = None;
+    let mut i = 0;
+    while i < args.len() {
+        let value = if args[i] == name {
+            if i + 1 == args.len() {
+                return Err(format!("{name} requires a value").into());
+            }
+            args.remove(i);
+            args.remove(i)
+        } else if let Some(value) = args[i].strip_prefix(name).and_then(|v| v.strip_prefix('=')) {
+            let value = value.to_string();
+            args.remove(i);
+            value
+        } else {
+            i += 1;
+            continue;
+        };
+
+        if option.replace(value).is_some() {
+            return Err(format!("{name} given more than once").into());
+        }
+    }
+    Ok(option)
+}
+
+/// Removes a flag without a value from the arguments and returns whether it was given.
+///
+/// # Errors
+///
+/// Returns an `Err` if the flag is given more than once.
+pub fn take_flag(args: &mut Vec<String>, name: &str) -> Result<bool> {
+    let count = args.len();
+    args.retain(|arg| arg != name);
+    match count - args.len() {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(format!("{name} given more than once").into()),
+    }
+}
+
+/// Removes `--input <path>` or `--input=<path>` from the arguments and returns the selected
+/// source.
+///
+/// # Errors
+///
+/// Returns an `Err` if the option is missing its value or given more than once.
+pub fn take_input_arg(args: &mut Vec<String>) -> Result<Option<Source>> {
+    Ok(take_option(args, INPUT_ARG)?.map(|value| Source::from_arg(&value)))
+}
+
+/// Finds the input of a day when none was given explicitly.
+///
+/// With an input directory the input is `<input_dir>/<year>/day_<dd>.txt`, otherwise it is the
+/// `input/input.txt` of the day's part 1 crate, which is searched for upwards from
+/// `manifest_dir`.
+///
+/// # Errors
+///
+/// Returns an `Err` if no directory for the year is found above `manifest_dir`.
+pub fn default_source(
+    input_dir: Option<&Path>,
+    manifest_dir: &Path,
+    year: u16,
+    day: u8,
+) -> Result<Source> {
+    if let Some(input_dir) = input_dir {
+        return Ok(Source::File(
+            input_dir
+                .join(year.to_string())
+                .join(format!("day_{day:02}.txt")),
+        ));
+    }
+
+    let year_dir = manifest_dir
+        .ancestors()
+        .map(|dir| dir.join(year.to_string()))
+        .find(|dir| dir.is_dir())
+        .ok_or_else(|| format!("no directory for {year} above {}", manifest_dir.display()))?;
+    Ok(Source::File(
+        year_dir
+            .join(format!("day_{day:02}_1"))
+            .join("input")
+            .join("input.txt"),
+    ))
+}
+
+/// Finds the input of a day from the environment or the workspace.
+///
+/// `manifest_dir` should be `env!("CARGO_MANIFEST_DIR")` of the calling crate.
+///
+/// # Errors
+///
+/// Returns an `Err` if the input cannot be found.
+pub fn locate(manifest_dir: &str, year: u16, day: u8) -> Result<Source> {
+    let input_dir = std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+    default_source(input_dir.as_deref(), Path::new(manifest_dir), year, day)
+}
+
+/// Resolves the input of a day from the command line, the environment or the workspace.
+///
+/// # Errors
+///
+/// Returns an `Err` if the arguments are invalid or the input cannot be found.
+pub fn resolve(args: &mut Vec<String>, manifest_dir: &str, year: u16, day: u8) -> Result<Source> {
+    match take_input_arg(args)? {
+        Some(source) => Ok(source),
+        None => locate(manifest_dir, year, day),
+    }
+}
+
+/// Loads the input of a day for a binary, honoring `--input` on its command line.
+///
+/// # Errors
+///
+/// Returns an `Err` if the input cannot be resolved or read.
+pub fn load(manifest_dir: &str, year: u16, day: u8) -> Result<String> {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    resolve(&mut args, manifest_dir, year, day)?.read()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_take_input_arg() {
+        let mut a = args(&["run", "--input", "in.txt", "2015"]);
+        let source = take_input_arg(&mut a).unwrap();
+        assert_eq!(source, Some(Source::File(PathBuf::from("in.txt"))));
+        assert_eq!(a, args(&["run", "2015"]));
+
+        let mut a = args(&["--input=-", "7"]);
+        assert_eq!(take_input_arg(&mut a).unwrap(), Some(Source::Stdin));
+        assert_eq!(a, args(&["7"]));
+
+        let mut a = args(&["run", "2015"]);
+        assert_eq!(take_input_arg(&mut a).unwrap(), None);
+        assert_eq!(a, args(&["run", "2015"]));
+    }
+
+    #[test]
+    fn test_take_option() {
+        let mut a = args(&["bench", "--samples=3", "--warmup", "0"]);
+        assert_eq!(
+            take_option(&mut a, "--warmup").unwrap().as_deref(),
+            Some("0")
+        );
+        assert_eq!(
+            take_option(&mut a, "--samples").unwrap().as_deref(),
+            Some("3")
+        );
+        assert_eq!(take_option(&mut a, "--output").unwrap(), None);
+        assert_eq!(a, args(&["bench"]));
+
+        // only the exact option name matches
+        let mut a = args(&["--samples-x=3"]);
+        assert_eq!(take_option(&mut a, "--samples").unwrap(), None);
+    }
+
+    #[test]
+    fn test_take_flag() {
+        let mut a = args(&["run", "--explain", "2015"]);
+        assert!(take_flag(&mut a, "--explain").unwrap());
+        assert!(!take_flag(&mut a, "--explain").unwrap());
+        assert_eq!(a, args(&["run", "2015"]));
+        assert!(take_flag(&mut args(&["--explain", "--explain"]), "--explain").is_err());
+    }
+
+    #[test]
+    fn test_take_input_arg_invalid() {
+        assert!(take_input_arg(&mut args(&["--input"])).is_err());
+        assert!(take_input_arg(&mut args(&["--input", "a", "--input=b"])).is_err());
+    }
+
+    #[test]
+    fn test_default_source_input_dir() {
+        let source = default_source(Some(Path::new("/inputs")), Path::new("/x"), 2015, 7).unwrap();
+        assert_eq!(
+            source,
+            Source::File(PathBuf::from("/inputs/2015/day_07.txt"))
+        );
+    }
+
+    #[test]
+    fn test_default_source_workspace() {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let source = default_source(None, manifest_dir, 2015, 1).unwrap();
+        let Source::File(path) = source else {
+            panic!("expected a file");
+        };
+        assert!(path.ends_with("2015/day_01_1/input/input.txt"));
+        assert!(!Source::File(path).read().unwrap().is_empty());
+
+        assert!(default_source(None, manifest_dir, 1900, 1).is_err());
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let error = Source::File(PathBuf::from("/does/not/exist"))
+            .read()
+            .unwrap_err();
+        assert!(error.to_string().contains("/does/not/exist"));
+    }
+}
@@ -1,3 +1,25 @@
+pub mod bitset;
+pub mod combinatorics;
+pub mod differential;
+pub mod error;
+pub mod examples;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod lines;
+pub mod memory;
+pub mod progress;
+pub mod search;
+pub mod solution;
+
+pub use bitset::BitSet;
+pub use error::{Error, Result};
+pub use grid::Grid;
+pub use lines::{parse_lines, LineParser};
+
 /// Converts an ASCII byte to a character.
 ///
 /// # Arguments
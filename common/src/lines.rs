@@ -0,0 +1,176 @@
+use crate::error::{Error, Result};
+
+/// Parses inputs with one value per line.
+///
+/// By default every line is parsed. Blank lines and comment lines can be skipped, they still count
+/// for the reported line numbers.
+///
+/// # Examples
+///
+/// ```
+/// use common::LineParser;
+///
+/// let parser = LineParser::new().skip_blank().skip_comments("#");
+/// let values: Vec<u8> = parser
+///     .parse_with("# header\n1\n\n2\n", |line| line.parse())
+///     .unwrap();
+/// assert_eq!(values, [1, 2]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineParser {
+    skip_blank: bool,
+    comment_prefix: Option<String>,
+}
+
+impl LineParser {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skips lines that are empty or only contain whitespace.
+    #[must_use]
+    pub fn skip_blank(mut self) -> Self {
+        self.skip_blank = true;
+        self
+    }
+
+    /// Skips lines starting with `prefix`, ignoring leading whitespace.
+    #[must_use]
+    pub fn skip_comments(mut self, prefix: impl Into<String>) -> Self {
+        self.comment_prefix = Some(prefix.into());
+        self
+    }
+
+    fn is_skipped(&self, line: &str) -> bool {
+        let trimmed = line.trim_start();
+        (self.skip_blank && trimmed.is_empty())
+            || self
+                .comment_prefix
+                .as_deref()
+                .is_some_and(|prefix| trimmed.starts_with(prefix))
+    }
+
+    /// Parses every line that is not skipped with `T::try_from`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` listing all lines that failed to parse.
+    pub fn parse<'a, T>(&self, input: &'a str) -> Result<Vec<T>>
+    where
+        T: TryFrom<&'a str>,
+        T::Error: Into<Error>,
+    {
+        self.parse_with(input, T::try_from)
+    }
+
+    /// Parses every line that is not skipped with the given function.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` listing all lines that failed to parse.
+    pub fn parse_with<'a, T, E: Into<Error>>(
+        &self,
+        input: &'a str,
+        mut parse: impl FnMut(&'a str) -> std::result::Result<T, E>,
+    ) -> Result<Vec<T>> {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            if self.is_skipped(line) {
+                continue;
+            }
+            match parse(line) {
+                Ok(value) => values.push(value),
+                Err(e) => errors.push(e.into().with_line(i + 1).with_text(line)),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(values)
+        } else {
+            Err(Error::multiple(errors))
+        }
+    }
+}
+
+/// Parses every line of the input with `T::try_from`.
+///
+/// See [`LineParser`] to skip blank or comment lines.
+///
+/// # Errors
+///
+/// Returns an `Err` listing all lines that failed to parse, with their line numbers and a caret
+/// under the failing column.
+pub fn parse_lines<'a, T>(input: &'a str) -> Result<Vec<T>>
+where
+    T: TryFrom<&'a str>,
+    T::Error: Into<Error>,
+{
+    LineParser::new().parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Digit(u8);
+
+    impl TryFrom<&str> for Digit {
+        type Error = Error;
+
+        fn try_from(input: &str) -> Result<Self> {
+            match input.find(|c: char| !c.is_ascii_digit()) {
+                Some(i) => Err(Error::new("not a digit").with_column(i + 1)),
+                None => Ok(Self(input.parse()?)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines("1\n2\n"), Ok(vec![Digit(1), Digit(2)]));
+        assert!(parse_lines::<Digit>("1\n\n2").is_err());
+    }
+
+    #[test]
+    fn test_parse_lines_reports_all_errors() {
+        let error = parse_lines::<Digit>("1\n2x\n3\n4y").unwrap_err();
+        let lines: Vec<_> = error.errors().iter().map(Error::line).collect();
+        assert_eq!(lines, [Some(2), Some(4)]);
+        assert_eq!(
+            error.to_string(),
+            "2 errors\n2:2: not a digit\n    2x\n     ^\n4:2: not a digit\n    4y\n     ^"
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_single_error() {
+        let error = parse_lines::<Digit>("1\nx").unwrap_err();
+        assert_eq!(error.to_string(), "2:1: not a digit\n    x\n    ^");
+    }
+
+    #[test]
+    fn test_skip() {
+        let input = "# digits\n1\n\n  # more digits\n2\n";
+        assert!(LineParser::new().parse::<Digit>(input).is_err());
+        assert!(LineParser::new()
+            .skip_blank()
+            .parse::<Digit>(input)
+            .is_err());
+        assert_eq!(
+            LineParser::new()
+                .skip_blank()
+                .skip_comments("#")
+                .parse(input),
+            Ok(vec![Digit(1), Digit(2)])
+        );
+
+        let error = LineParser::new()
+            .skip_comments("#")
+            .parse::<Digit>(input)
+            .unwrap_err();
+        assert_eq!(error.line(), Some(3));
+    }
+}
@@ -0,0 +1,158 @@
+//! Measuring how much memory a solution allocates.
+//!
+//! [`CountingAllocator`] wraps the system allocator and keeps count of the bytes that are
+//! allocated. It only counts once a binary installs it as its global allocator:
+//!
+//! ```
+//! use common::memory::{self, CountingAllocator};
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator = CountingAllocator;
+//!
+//! let (numbers, usage) = memory::measure(|| vec![0_u64; 1000]);
+//! assert_eq!(numbers.len(), 1000);
+//! assert_eq!(usage.peak, 8000);
+//! assert_eq!(usage.total, 8000);
+//! ```
+//!
+//! The counters are shared by all threads, so [`measure`] also sees what other threads allocate
+//! in the meantime, and measurements must not be nested.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The bytes allocated right now.
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+/// The most bytes allocated at once since the last reset.
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+/// The bytes ever allocated, counting the growth of reallocations.
+static TOTAL: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting the bytes it allocates.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CountingAllocator;
+
+fn grow(bytes: usize) {
+    let current = CURRENT.fetch_add(bytes, Ordering::Relaxed) + bytes;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+    TOTAL.fetch_add(bytes, Ordering::Relaxed);
+}
+
+fn shrink(bytes: usize) {
+    CURRENT.fetch_sub(bytes, Ordering::Relaxed);
+}
+
+// SAFETY: every call is forwarded to `System` unchanged, the counters only observe the sizes.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            if new_size > layout.size() {
+                grow(new_size - layout.size());
+            } else {
+                shrink(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// The memory allocated while running a closure, in bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// The most bytes that were allocated at once, beyond what was allocated before.
+    pub peak: usize,
+    /// The bytes allocated in total, including those that were freed again.
+    pub total: usize,
+}
+
+/// Formats a number of bytes with a binary prefix, e.g. `1.5 MiB`.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "peak {}, {} allocated",
+            format_bytes(self.peak),
+            format_bytes(self.total)
+        )
+    }
+}
+
+/// Runs `f` and returns how much memory it allocated.
+///
+/// Without a [`CountingAllocator`] as the global allocator the usage is always zero.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let start = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(start, Ordering::Relaxed);
+    let start_total = TOTAL.load(Ordering::Relaxed);
+
+    let result = f();
+
+    let usage = Usage {
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(start),
+        total: TOTAL.load(Ordering::Relaxed).wrapping_sub(start_total),
+    };
+    (result, usage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_without_allocator() {
+        let (numbers, usage) = measure(|| vec![0_u8; 1000]);
+        assert_eq!(numbers.len(), 1000);
+        assert_eq!(usage, Usage::default());
+    }
+
+    #[test]
+    fn test_display() {
+        let usage = Usage {
+            peak: 512,
+            total: 3 * 1024 * 1024 / 2,
+        };
+        assert_eq!(usage.to_string(), "peak 512 B, 1.5 MiB allocated");
+        assert_eq!(format_bytes(1024), "1.0 KiB");
+        assert_eq!(format_bytes(usize::MAX), "16777216.0 TiB");
+    }
+}
@@ -0,0 +1,376 @@
+//! Progress reports and cooperative cancellation for long-running searches.
+//!
+//! `Solution` has no room for extra arguments, so the caller installs a [`Progress`] for the
+//! current thread with [`Progress::run`], and a long loop picks up a [`Reporter`] with [`current`].
+//! The loop then calls [`Reporter::report`] every now and then, which forwards the progress to the
+//! caller and returns an `Err` once the search was cancelled or ran out of time. Without an
+//! installed `Progress` reporting does nothing, so the loops cost about the same as before in tests
+//! and the per-day binaries.
+//!
+//! A `Reporter` can be cloned into other threads, e.g. the workers of a parallel iterator.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use common::progress::{self, Progress};
+//!
+//! fn count_to(n: u64) -> common::Result<u64> {
+//!     let reporter = progress::current();
+//!     for i in 0..n {
+//!         reporter.report("counting", i, Some(n))?;
+//!     }
+//!     Ok(n)
+//! }
+//!
+//! assert_eq!(count_to(1000).unwrap(), 1000);
+//!
+//! let timed_out = Progress::new().with_timeout(Some(Duration::ZERO)).run(|| count_to(1000));
+//! assert!(timed_out.is_err());
+//! ```
+
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+
+/// A flag to stop a search from another thread.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes every `Reporter` of a `Progress` with this token return an `Err` from now on.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// How far a search got, as passed to the callback of a `Progress`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Update<'a> {
+    /// What is being counted, e.g. `hashes`.
+    pub label: &'a str,
+    pub done: u64,
+    /// The number of steps in total, if known in advance.
+    pub total: Option<u64>,
+    /// The time since the `Progress` was installed.
+    pub elapsed: Duration,
+}
+
+impl Update<'_> {
+    /// The fraction of the steps done, if the total is known.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn fraction(&self) -> Option<f64> {
+        self.total
+            .filter(|&total| total > 0)
+            .map(|total| self.done.min(total) as f64 / total as f64)
+    }
+
+    /// The estimated time until all steps are done, assuming they keep taking as long as so far.
+    #[must_use]
+    pub fn eta(&self) -> Option<Duration> {
+        let fraction = self.fraction().filter(|&fraction| fraction > 0.0)?;
+        Some(self.elapsed.mul_f64((1.0 - fraction) / fraction))
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    if seconds == 0 {
+        format!("{}ms", duration.as_millis())
+    } else if seconds < 60 {
+        format!("{seconds}s")
+    } else {
+        format!("{}m {:02}s", seconds / 60, seconds % 60)
+    }
+}
+
+impl Display for Update<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.done, self.label)?;
+        if let (Some(total), Some(fraction)) = (self.total, self.fraction()) {
+            write!(f, " of {total} ({:.0}%)", fraction * 100.0)?;
+        }
+        write!(f, ", {} elapsed", format_duration(self.elapsed))?;
+        if let Some(eta) = self.eta() {
+            write!(f, ", about {} left", format_duration(eta))?;
+        }
+        Ok(())
+    }
+}
+
+type Callback = Box<dyn Fn(&Update) + Send + Sync>;
+
+/// Where the reports of long-running searches go, and when they should stop.
+pub struct Progress {
+    token: CancelToken,
+    timeout: Option<Duration>,
+    interval: Duration,
+    on_update: Option<Callback>,
+}
+
+impl Default for Progress {
+    fn default() -> Self {
+        Self {
+            token: CancelToken::default(),
+            timeout: None,
+            interval: Duration::from_millis(100),
+            on_update: None,
+        }
+    }
+}
+
+impl Progress {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops the searches once `token` is cancelled.
+    #[must_use]
+    pub fn with_token(mut self, token: CancelToken) -> Self {
+        self.token = token;
+        self
+    }
+
+    /// Stops the searches once `timeout` has passed since `run` was called.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the minimum time between two calls of the callback, 100 ms by default.
+    #[must_use]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Calls `on_update` with the reports of the searches, at most once per interval.
+    #[must_use]
+    pub fn on_update(mut self, on_update: impl Fn(&Update) + Send + Sync + 'static) -> Self {
+        self.on_update = Some(Box::new(on_update));
+        self
+    }
+
+    /// Runs `f` with this progress installed for the current thread, restoring the previous one
+    /// afterwards.
+    pub fn run<T>(self, f: impl FnOnce() -> T) -> T {
+        struct Restore(Reporter);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = std::mem::take(&mut self.0);
+                CURRENT.with(|current| *current.borrow_mut() = previous);
+            }
+        }
+
+        let start = Instant::now();
+        let reporter = Reporter(Some(Arc::new(Context {
+            progress: self,
+            start,
+            last_update: Mutex::new(start),
+        })));
+        let _restore = Restore(CURRENT.with(|current| current.replace(reporter)));
+        f()
+    }
+}
+
+struct Context {
+    progress: Progress,
+    start: Instant,
+    last_update: Mutex<Instant>,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Reporter> = RefCell::default();
+}
+
+/// The reporter of the `Progress` installed for the current thread, or one that does nothing.
+#[must_use]
+pub fn current() -> Reporter {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// The handle a search reports its progress to, see [`current`].
+#[derive(Clone, Default)]
+pub struct Reporter(Option<Arc<Context>>);
+
+impl Reporter {
+    /// Checks whether the search should stop.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the search was cancelled or ran out of time.
+    pub fn check(&self) -> Result<()> {
+        let Some(context) = &self.0 else {
+            return Ok(());
+        };
+        if context.progress.token.is_cancelled() {
+            return Err(Error::new("cancelled"));
+        }
+        match context.progress.timeout {
+            Some(timeout) if context.start.elapsed() >= timeout => Err(Error::new(format!(
+                "timed out after {}",
+                format_duration(timeout)
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Reports that `done` of `total` steps are done and checks whether the search should stop.
+    ///
+    /// The callback is skipped if it was called less than an interval ago, or if another thread is
+    /// calling it right now.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the search was cancelled or ran out of time.
+    pub fn report(&self, label: &str, done: u64, total: Option<u64>) -> Result<()> {
+        self.check()?;
+        let Some(context) = &self.0 else {
+            return Ok(());
+        };
+        let Some(on_update) = &context.progress.on_update else {
+            return Ok(());
+        };
+        if let Ok(mut last_update) = context.last_update.try_lock() {
+            let now = Instant::now();
+            if now.duration_since(*last_update) >= context.progress.interval {
+                *last_update = now;
+                on_update(&Update {
+                    label,
+                    done,
+                    total,
+                    elapsed: now.duration_since(context.start),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_without_progress() {
+        let reporter = current();
+        assert_eq!(reporter.check(), Ok(()));
+        assert_eq!(reporter.report("steps", 1, None), Ok(()));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let token = CancelToken::new();
+        let progress = Progress::new().with_token(token.clone());
+        progress.run(|| {
+            let reporter = current();
+            assert_eq!(reporter.check(), Ok(()));
+            token.cancel();
+            assert_eq!(reporter.check(), Err(Error::new("cancelled")));
+            // also seen from other threads
+            std::thread::spawn(move || assert!(reporter.report("steps", 1, None).is_err()))
+                .join()
+                .unwrap();
+        });
+        assert_eq!(current().check(), Ok(()));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let result = Progress::new()
+            .with_timeout(Some(Duration::ZERO))
+            .run(|| current().check());
+        assert_eq!(result, Err(Error::new("timed out after 0ms")));
+
+        let result = Progress::new()
+            .with_timeout(Some(Duration::from_secs(60)))
+            .run(|| current().check());
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_updates() {
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&updates);
+        let progress = Progress::new()
+            .with_interval(Duration::ZERO)
+            .on_update(move |update| {
+                seen.lock()
+                    .unwrap()
+                    .push((update.label.to_string(), update.done, update.total));
+            });
+        progress.run(|| {
+            for i in 1..=3 {
+                current().report("steps", i, Some(3)).unwrap();
+            }
+        });
+        assert_eq!(
+            *updates.lock().unwrap(),
+            [
+                ("steps".to_string(), 1, Some(3)),
+                ("steps".to_string(), 2, Some(3)),
+                ("steps".to_string(), 3, Some(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nested() {
+        Progress::new().run(|| {
+            Progress::new()
+                .with_timeout(Some(Duration::ZERO))
+                .run(|| assert!(current().check().is_err()));
+            assert_eq!(current().check(), Ok(()));
+        });
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::ZERO), "0ms");
+        assert_eq!(format_duration(Duration::from_millis(50)), "50ms");
+        assert_eq!(format_duration(Duration::from_millis(1500)), "1s");
+        assert_eq!(format_duration(Duration::from_secs(90)), "1m 30s");
+    }
+
+    #[test]
+    fn test_update() {
+        let update = Update {
+            label: "hashes",
+            done: 250,
+            total: Some(1000),
+            elapsed: Duration::from_secs(30),
+        };
+        assert_eq!(update.fraction(), Some(0.25));
+        assert_eq!(update.eta(), Some(Duration::from_secs(90)));
+        assert_eq!(
+            update.to_string(),
+            "250 hashes of 1000 (25%), 30s elapsed, about 1m 30s left"
+        );
+
+        let update = Update {
+            total: None,
+            ..update
+        };
+        assert_eq!(update.eta(), None);
+        assert_eq!(update.to_string(), "250 hashes, 30s elapsed");
+    }
+}
@@ -0,0 +1,433 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Counters describing how much work a search did.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Nodes whose successors were generated.
+    pub expanded: usize,
+    /// Successors returned by the successor function, including duplicates.
+    pub generated: usize,
+    /// The largest number of nodes waiting to be expanded at once.
+    pub max_frontier: usize,
+}
+
+/// A path from the start node to a goal node, both included.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+/// The result of a search: the path found, if any, and the work it took.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Outcome<N, C> {
+    pub path: Option<Path<N, C>>,
+    pub stats: Stats,
+}
+
+impl<N, C: Copy> Outcome<N, C> {
+    /// The cost of the path found, if any.
+    #[must_use]
+    pub fn cost(&self) -> Option<C> {
+        self.path.as_ref().map(|path| path.cost)
+    }
+}
+
+struct Node<N, C> {
+    node: N,
+    parent: Option<usize>,
+    cost: C,
+}
+
+/// All nodes reached so far, each with the parent it was reached from.
+struct Tree<N, C> {
+    nodes: Vec<Node<N, C>>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy + Ord> Tree<N, C> {
+    fn new(start: N, cost: C) -> Self {
+        Self {
+            indices: HashMap::from([(start.clone(), 0)]),
+            nodes: vec![Node {
+                node: start,
+                parent: None,
+                cost,
+            }],
+        }
+    }
+
+    /// Records that `node` can be reached from `parent` at `cost`.
+    ///
+    /// Returns the index of the node if it is new or cheaper than before.
+    fn insert(&mut self, node: N, parent: usize, cost: C) -> Option<usize> {
+        match self.indices.entry(node) {
+            Entry::Occupied(entry) => {
+                let index = *entry.get();
+                let known = &mut self.nodes[index];
+                if cost >= known.cost {
+                    return None;
+                }
+                known.parent = Some(parent);
+                known.cost = cost;
+                Some(index)
+            }
+            Entry::Vacant(entry) => {
+                let index = self.nodes.len();
+                self.nodes.push(Node {
+                    node: entry.key().clone(),
+                    parent: Some(parent),
+                    cost,
+                });
+                entry.insert(index);
+                Some(index)
+            }
+        }
+    }
+
+    fn path(&self, mut index: usize) -> Path<N, C> {
+        let cost = self.nodes[index].cost;
+        let mut nodes = vec![self.nodes[index].node.clone()];
+        while let Some(parent) = self.nodes[index].parent {
+            nodes.push(self.nodes[parent].node.clone());
+            index = parent;
+        }
+        nodes.reverse();
+        Path { nodes, cost }
+    }
+}
+
+/// Breadth-first search for a path with the fewest steps from `start` to a goal.
+///
+/// Each node is expanded at most once. The cost of the path is its number of steps.
+///
+/// # Examples
+///
+/// ```
+/// use common::search::bfs;
+///
+/// // Reach 10 from 1 by doubling or adding one.
+/// let outcome = bfs(1, |&n: &u32| [n * 2, n + 1], |&n| n == 10);
+/// let path = outcome.path.unwrap();
+/// assert_eq!(path.nodes, [1, 2, 4, 5, 10]);
+/// assert_eq!(path.cost, 4);
+/// ```
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Outcome<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree = Tree::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+    let mut stats = Stats::default();
+
+    while let Some(index) = queue.pop_front() {
+        let node = &tree.nodes[index];
+        if is_goal(&node.node) {
+            return Outcome {
+                path: Some(tree.path(index)),
+                stats,
+            };
+        }
+
+        stats.expanded += 1;
+        let cost = node.cost + 1;
+        for successor in successors(&node.node).into_iter().collect::<Vec<_>>() {
+            stats.generated += 1;
+            if let Some(successor) = tree.insert(successor, index, cost) {
+                queue.push_back(successor);
+            }
+        }
+        stats.max_frontier = stats.max_frontier.max(queue.len());
+    }
+
+    Outcome { path: None, stats }
+}
+
+/// Dijkstra's algorithm for the cheapest path from `start` to a goal.
+///
+/// The successor function returns each neighbour with the cost of the step there, which must
+/// not be negative.
+///
+/// # Examples
+///
+/// ```
+/// use common::search::dijkstra;
+///
+/// let edges = [('a', 'b', 7), ('a', 'c', 2), ('c', 'b', 3), ('b', 'd', 1)];
+/// let outcome = dijkstra(
+///     'a',
+///     |&from| edges.iter().filter(move |e| e.0 == from).map(|e| (e.1, e.2)),
+///     |&node| node == 'd',
+/// );
+/// assert_eq!(outcome.cost(), Some(6));
+/// assert_eq!(outcome.path.unwrap().nodes, ['a', 'c', 'b', 'd']);
+/// ```
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Outcome<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// A* search for the cheapest path from `start` to a goal.
+///
+/// `heuristic` estimates the remaining cost to a goal. The path found is the cheapest one as long
+/// as the estimate never exceeds the actual cost.
+///
+/// # Examples
+///
+/// ```
+/// use common::geometry::{Dir4, Point2};
+/// use common::search::astar;
+///
+/// let goal = Point2::new(3_i32, 4);
+/// let outcome = astar(
+///     Point2::new(0, 0),
+///     |&point| Dir4::ALL.map(|d| (point + d, 1)),
+///     |point| point.manhattan(goal),
+///     |&point| point == goal,
+/// );
+/// assert_eq!(outcome.cost(), Some(7));
+/// ```
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Outcome<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut tree = Tree::new(start, C::default());
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&tree.nodes[0].node), C::default(), 0))]);
+    let mut stats = Stats::default();
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let node = &tree.nodes[index];
+        if cost > node.cost {
+            // A cheaper way to this node was found after this entry was queued.
+            continue;
+        }
+        if is_goal(&node.node) {
+            return Outcome {
+                path: Some(tree.path(index)),
+                stats,
+            };
+        }
+
+        stats.expanded += 1;
+        for (successor, step) in successors(&node.node).into_iter().collect::<Vec<_>>() {
+            stats.generated += 1;
+            let cost = cost + step;
+            if let Some(successor) = tree.insert(successor, index, cost) {
+                let estimate = cost + heuristic(&tree.nodes[successor].node);
+                queue.push(Reverse((estimate, cost, successor)));
+            }
+        }
+        stats.max_frontier = stats.max_frontier.max(queue.len());
+    }
+
+    Outcome { path: None, stats }
+}
+
+/// Depth-first branch-and-bound search for the cheapest path of at most `max_depth` steps.
+///
+/// Branches are cut as soon as they cost at least as much as the best path found so far, so step
+/// costs must not be negative. A node is skipped when it was already reached at most as cheaply in
+/// at most as many steps.
+///
+/// # Examples
+///
+/// ```
+/// use common::search::branch_and_bound;
+///
+/// // Pay for a total of 11 with coins of 1, 5 and 7, where every coin costs one.
+/// let outcome = branch_and_bound(
+///     0,
+///     |&total: &u32| [1, 5, 7].map(|coin| (total + coin, 1)).into_iter().filter(|&(t, _)| t <= 11),
+///     |&total| total == 11,
+///     5,
+/// );
+/// assert_eq!(outcome.cost(), Some(3));
+/// ```
+pub fn branch_and_bound<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+    max_depth: usize,
+) -> Outcome<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // Unlike the other searches, different branches may reach the same node with different
+    // depths, so every visit gets its own entry and `seen` only keeps the best known visits.
+    let mut nodes = vec![Node {
+        node: start.clone(),
+        parent: None,
+        cost: C::default(),
+    }];
+    let mut seen = HashMap::from([(start, vec![(C::default(), 0)])]);
+    let mut stack = vec![(0, 0)];
+    let mut best: Option<usize> = None;
+    let mut stats = Stats::default();
+
+    while let Some((index, depth)) = stack.pop() {
+        let cost = nodes[index].cost;
+        if best.is_some_and(|best| cost >= nodes[best].cost) {
+            continue;
+        }
+        if is_goal(&nodes[index].node) {
+            best = Some(index);
+            continue;
+        }
+        if depth == max_depth {
+            continue;
+        }
+
+        stats.expanded += 1;
+        for (successor, step) in successors(&nodes[index].node)
+            .into_iter()
+            .collect::<Vec<_>>()
+        {
+            stats.generated += 1;
+            let cost = cost + step;
+            if best.is_some_and(|best| cost >= nodes[best].cost) {
+                continue;
+            }
+
+            let visits = seen.entry(successor.clone()).or_default();
+            if visits.iter().any(|&(c, d)| c <= cost && d <= depth + 1) {
+                continue;
+            }
+            visits.retain(|&(c, d)| c < cost || d < depth + 1);
+            visits.push((cost, depth + 1));
+
+            stack.push((nodes.len(), depth + 1));
+            nodes.push(Node {
+                node: successor,
+                parent: Some(index),
+                cost,
+            });
+        }
+        stats.max_frontier = stats.max_frontier.max(stack.len());
+    }
+
+    let path = best.map(|mut index| {
+        let cost = nodes[index].cost;
+        let mut path = vec![nodes[index].node.clone()];
+        while let Some(parent) = nodes[index].parent {
+            path.push(nodes[parent].node.clone());
+            index = parent;
+        }
+        path.reverse();
+        Path { nodes: path, cost }
+    });
+    Outcome { path, stats }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.####.#.
+......#G
+";
+
+    fn maze() -> (Grid<char>, (usize, usize), (usize, usize)) {
+        let grid = Grid::parse(MAZE, Ok::<_, crate::Error>).unwrap();
+        let find = |c| grid.positions().find(|&p| grid[p] == c).unwrap();
+        (grid.clone(), find('S'), find('G'))
+    }
+
+    #[test]
+    fn test_bfs_maze() {
+        let (grid, start, goal) = maze();
+        let outcome = bfs(
+            start,
+            |&p| {
+                grid.neighbors4(p)
+                    .filter(|&n| grid[n] != '#')
+                    .collect::<Vec<_>>()
+            },
+            |&p| p == goal,
+        );
+        let path = outcome.path.unwrap();
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.nodes.len(), 16);
+        assert_eq!(path.nodes.first(), Some(&start));
+        assert_eq!(path.nodes.last(), Some(&goal));
+        assert!(path
+            .nodes
+            .windows(2)
+            .all(|w| w[0].0.abs_diff(w[1].0) + w[0].1.abs_diff(w[1].1) == 1));
+        assert!(outcome.stats.expanded <= grid.values().filter(|&&c| c != '#').count());
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let outcome = bfs(0_u8, |&n| (n < 10).then_some(n + 1), |&n| n == 20);
+        assert_eq!(outcome.path, None);
+        assert_eq!(outcome.stats.expanded, 11);
+
+        let outcome = dijkstra(0_u8, |&n| (n < 10).then_some((n + 1, 1_u32)), |&n| n == 20);
+        assert_eq!(outcome.cost(), None);
+    }
+
+    #[test]
+    fn test_weighted_searches_agree() {
+        let (grid, start, goal) = maze();
+        // Moving down is expensive.
+        let successors = |&p: &(usize, usize)| {
+            grid.neighbors4(p)
+                .filter(|&n| grid[n] != '#')
+                .map(move |n| (n, if n.1 > p.1 { 3 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+        let heuristic = |p: &(usize, usize)| p.0.abs_diff(goal.0) + p.1.abs_diff(goal.1);
+
+        let uniform = dijkstra(start, successors, |&p| p == goal);
+        let guided = astar(start, successors, heuristic, |&p| p == goal);
+        let bounded = branch_and_bound(start, successors, |&p| p == goal, 30);
+
+        assert_eq!(uniform.cost(), Some(27));
+        assert_eq!(guided.cost(), uniform.cost());
+        assert_eq!(bounded.cost(), uniform.cost());
+        assert!(guided.stats.expanded <= uniform.stats.expanded);
+    }
+
+    #[test]
+    fn test_branch_and_bound_max_depth() {
+        // The cheap way to 6 takes six steps, the expensive one two.
+        let successors = |&n: &u32| [(n + 1, 1), (n + 3, 5)];
+        let outcome = branch_and_bound(0, successors, |&n| n == 6, 6);
+        assert_eq!(outcome.cost(), Some(6));
+        let outcome = branch_and_bound(0, successors, |&n| n == 6, 2);
+        assert_eq!(outcome.cost(), Some(10));
+        assert_eq!(outcome.path.unwrap().nodes, [0, 3, 6]);
+        assert_eq!(branch_and_bound(0, successors, |&n| n == 6, 1).path, None);
+    }
+}
@@ -0,0 +1,574 @@
+use std::any::Any;
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+use crate::generate::Rng;
+
+pub use crate::error::{Error, Result};
+
+/// One of the (up to) two parts of a puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts in order.
+    pub const ALL: [Self; 2] = [Self::One, Self::Two];
+
+    /// The number of the part as used by the puzzle site.
+    #[must_use]
+    pub fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = Error;
+
+    fn try_from(number: u8) -> Result<Self> {
+        match number {
+            1 => Ok(Self::One),
+            2 => Ok(Self::Two),
+            _ => Err(format!("invalid part: {number}").into()),
+        }
+    }
+}
+
+impl std::str::FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.parse::<u8>()
+            .map_err(|_| format!("invalid part: {s}"))?
+            .try_into()
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// How an answer was found, e.g. the route taken or the items chosen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Work {
+    /// Steps in the order they were taken, such as a route or a sequence of spells.
+    Steps { label: String, steps: Vec<String> },
+    /// A selection of items in no particular order, such as a loadout.
+    Items { label: String, items: Vec<String> },
+}
+
+impl Work {
+    pub fn steps<T: Display>(label: impl Into<String>, steps: impl IntoIterator<Item = T>) -> Self {
+        Self::Steps {
+            label: label.into(),
+            steps: steps.into_iter().map(|step| step.to_string()).collect(),
+        }
+    }
+
+    pub fn items<T: Display>(label: impl Into<String>, items: impl IntoIterator<Item = T>) -> Self {
+        Self::Items {
+            label: label.into(),
+            items: items.into_iter().map(|item| item.to_string()).collect(),
+        }
+    }
+}
+
+impl Display for Work {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Steps { label, steps } => write!(f, "{label}: {}", steps.join(" -> ")),
+            Self::Items { label, items } => write!(f, "{label}: {}", items.join(", ")),
+        }
+    }
+}
+
+/// An answer together with the work that led to it.
+///
+/// It displays as the bare value, so it can be used as the answer of a `Solution` wherever a
+/// plain value would be.
+///
+/// # Examples
+///
+/// ```
+/// use common::solution::{Answer, Explain, Work};
+///
+/// let answer = Answer::new(605).with_work(Work::steps("route", ["London", "Dublin", "Belfast"]));
+/// assert_eq!(answer.to_string(), "605");
+/// assert_eq!(
+///     answer.work().unwrap().to_string(),
+///     "route: London -> Dublin -> Belfast"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer<T> {
+    pub value: T,
+    pub work: Option<Work>,
+}
+
+impl<T> Answer<T> {
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self { value, work: None }
+    }
+
+    #[must_use]
+    pub fn with_work(mut self, work: Work) -> Self {
+        self.work = Some(work);
+        self
+    }
+}
+
+impl<T: Display> Display for Answer<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+/// The answer of a part, which may explain how it was found.
+pub trait Explain: Display {
+    /// The work that led to the answer, if it is known.
+    fn work(&self) -> Option<&Work> {
+        None
+    }
+}
+
+impl<T: Display> Explain for Answer<T> {
+    fn work(&self) -> Option<&Work> {
+        self.work.as_ref()
+    }
+}
+
+macro_rules! impl_explain {
+    ($($t:ty),*) => {
+        $(impl Explain for $t {})*
+    };
+}
+
+impl_explain!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, String, &str);
+
+/// A solution for a single day of a year.
+///
+/// The puzzle input is parsed once and then handed to both parts.
+pub trait Solution {
+    /// The year of the puzzle.
+    const YEAR: u16;
+
+    /// The day of the puzzle.
+    const DAY: u8;
+
+    /// The parts that this day consists of (the last day of a year only has one).
+    const PARTS: &'static [Part] = &Part::ALL;
+
+    /// The `size` at which `generate` produces inputs about as large as the real one.
+    const INPUT_SIZE: usize = 100;
+
+    /// The parsed puzzle input.
+    type Input;
+
+    /// The answer to the first part.
+    type Answer1: Explain;
+
+    /// The answer to the second part.
+    type Answer2: Explain;
+
+    /// Parses the raw puzzle input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input is malformed.
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Solves the first part.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if no answer can be found for the given input.
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+
+    /// Solves the second part.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if no answer can be found for the given input.
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// Generates a random, well-formed input, or `None` if the day has no generator.
+    ///
+    /// What `size` counts depends on the day (lines, wires, cities, ...), but the input grows
+    /// with it.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let _ = (rng, size);
+        None
+    }
+
+    /// Serializes a parsed input as JSON, e.g. with `to_json` when the `serde` feature is
+    /// enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the day cannot serialize its input.
+    fn input_json(input: &Self::Input) -> Result<String> {
+        let _ = input;
+        Err(format!(
+            "{} day {} cannot serialize its input",
+            Self::YEAR,
+            Self::DAY
+        )
+        .into())
+    }
+}
+
+/// A type-erased `Solution` so that solutions of all days can be kept in a single registry.
+pub trait DynSolution: Sync {
+    /// The year of the puzzle.
+    fn year(&self) -> u16;
+
+    /// The day of the puzzle.
+    fn day(&self) -> u8;
+
+    /// The parts that this day consists of.
+    fn parts(&self) -> &'static [Part];
+
+    /// Parses the raw puzzle input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input is malformed.
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    /// Solves a part for an input previously returned by `parse`, keeping the work if the
+    /// solution explains its answer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input has the wrong type, the part does not exist or no answer can
+    /// be found.
+    fn explain(&self, input: &dyn Any, part: Part) -> Result<Answer<String>>;
+
+    /// Solves a part for an input previously returned by `parse`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input has the wrong type, the part does not exist or no answer can
+    /// be found.
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<String> {
+        self.explain(input, part).map(|answer| answer.value)
+    }
+
+    /// Parses the raw puzzle input and solves a part.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if either parsing or solving fails.
+    fn run(&self, input: &str, part: Part) -> Result<String> {
+        self.solve(self.parse(input)?.as_ref(), part)
+    }
+
+    /// The `size` at which `generate` produces inputs about as large as the real one.
+    fn input_size(&self) -> usize;
+
+    /// Generates a random, well-formed input, or `None` if the day has no generator.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String>;
+
+    /// Serializes an input previously returned by `parse` as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the input has the wrong type or the day cannot serialize its input.
+    fn input_json(&self, input: &dyn Any) -> Result<String>;
+}
+
+/// Registers a `Solution` as a `DynSolution`.
+///
+/// # Examples
+///
+/// ```
+/// use common::solution::{DynSolution, Part, Registered, Result, Solution};
+///
+/// struct Length;
+///
+/// impl Solution for Length {
+///     const YEAR: u16 = 2015;
+///     const DAY: u8 = 1;
+///
+///     type Input = String;
+///     type Answer1 = usize;
+///     type Answer2 = usize;
+///
+///     fn parse(input: &str) -> Result<String> {
+///         Ok(input.trim().to_string())
+///     }
+///
+///     fn part1(input: &String) -> Result<usize> {
+///         Ok(input.len())
+///     }
+///
+///     fn part2(input: &String) -> Result<usize> {
+///         Ok(input.len() * 2)
+///     }
+/// }
+///
+/// const REGISTRY: &[&dyn DynSolution] = &[&Registered::<Length>::new()];
+/// assert_eq!(REGISTRY[0].run("abc\n", Part::Two).unwrap(), "6");
+/// ```
+pub struct Registered<S>(PhantomData<fn() -> S>);
+
+impl<S> Registered<S> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Default for Registered<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> DynSolution for Registered<S>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        S::PARTS
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn explain(&self, input: &dyn Any, part: Part) -> Result<Answer<String>> {
+        fn erase(answer: &impl Explain) -> Answer<String> {
+            Answer {
+                value: answer.to_string(),
+                work: answer.work().cloned(),
+            }
+        }
+
+        if !S::PARTS.contains(&part) {
+            return Err(format!("{} day {} has no part {part}", S::YEAR, S::DAY).into());
+        }
+        let input = input
+            .downcast_ref::<S::Input>()
+            .ok_or("input was not parsed by this solution")?;
+        match part {
+            Part::One => S::part1(input).map(|answer| erase(&answer)),
+            Part::Two => S::part2(input).map(|answer| erase(&answer)),
+        }
+    }
+
+    fn input_size(&self) -> usize {
+        S::INPUT_SIZE
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        S::generate(rng, size)
+    }
+
+    fn input_json(&self, input: &dyn Any) -> Result<String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .ok_or("input was not parsed by this solution")?;
+        S::input_json(input)
+    }
+}
+
+/// Serializes a value as pretty-printed JSON.
+///
+/// # Errors
+///
+/// Returns an `Err` if the value cannot be represented as JSON, e.g. a map with non-string keys.
+#[cfg(feature = "serde")]
+pub fn to_json<T: serde::Serialize + ?Sized>(value: &T) -> Result<String> {
+    serde_json::to_string_pretty(value).map_err(|e| Error::new(e.to_string()))
+}
+
+/// Finds the solution for a given day in a registry.
+#[must_use]
+pub fn find<'a>(
+    registry: &[&'a dyn DynSolution],
+    year: u16,
+    day: u8,
+) -> Option<&'a dyn DynSolution> {
+    registry
+        .iter()
+        .find(|solution| solution.year() == year && solution.day() == day)
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+
+        type Input = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = Answer<u32>;
+
+        fn parse(input: &str) -> Result<Vec<u32>> {
+            Ok(input
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<std::result::Result<_, _>>()?)
+        }
+
+        fn part1(input: &Vec<u32>) -> Result<u32> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Vec<u32>) -> Result<Answer<u32>> {
+            let (position, max) = input
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, value)| **value)
+                .ok_or("empty")?;
+            Ok(Answer::new(*max).with_work(Work::items("position", [position])))
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+            let numbers: Vec<_> = (0..size).map(|_| rng.range(0..=9).to_string()).collect();
+            Some(numbers.join(" "))
+        }
+
+        fn input_json(input: &Vec<u32>) -> Result<String> {
+            let numbers: Vec<_> = input.iter().map(u32::to_string).collect();
+            Ok(format!("[{}]", numbers.join(",")))
+        }
+    }
+
+    struct Last;
+
+    impl Solution for Last {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 25;
+        const PARTS: &'static [Part] = &[Part::One];
+
+        type Input = ();
+        type Answer1 = &'static str;
+        type Answer2 = &'static str;
+
+        fn parse(_: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn part1(_: &()) -> Result<&'static str> {
+            Ok("done")
+        }
+
+        fn part2(_: &()) -> Result<&'static str> {
+            unreachable!()
+        }
+    }
+
+    const REGISTRY: &[&dyn DynSolution] = &[&Registered::<Sum>::new(), &Registered::<Last>::new()];
+
+    #[test]
+    fn test_part() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+        assert!("x".parse::<Part>().is_err());
+        assert_eq!(Part::Two.to_string(), "2");
+    }
+
+    #[test]
+    fn test_run() {
+        let sum = find(REGISTRY, 2015, 1).unwrap();
+        assert_eq!(sum.parts(), &[Part::One, Part::Two]);
+        assert_eq!(sum.run("1 2 3", Part::One).unwrap(), "6");
+        assert_eq!(sum.run("1 2 3", Part::Two).unwrap(), "3");
+        assert!(sum.run("", Part::Two).is_err());
+        assert!(sum.run("1 x", Part::One).is_err());
+    }
+
+    #[test]
+    fn test_explain() {
+        let sum = find(REGISTRY, 2015, 1).unwrap();
+        let input = sum.parse("1 5 3").unwrap();
+        assert_eq!(
+            sum.explain(input.as_ref(), Part::One).unwrap(),
+            Answer::new("9".to_string())
+        );
+        assert_eq!(
+            sum.explain(input.as_ref(), Part::Two).unwrap(),
+            Answer::new("5".to_string()).with_work(Work::items("position", [1]))
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let sum = find(REGISTRY, 2015, 1).unwrap();
+        let input = sum.generate(&mut Rng::new(1), 4).unwrap();
+        assert_eq!(input, sum.generate(&mut Rng::new(1), 4).unwrap());
+        assert_eq!(input.split(' ').count(), 4);
+        assert!(sum.run(&input, Part::One).is_ok());
+
+        let last = find(REGISTRY, 2015, 25).unwrap();
+        assert_eq!(last.generate(&mut Rng::new(1), 4), None);
+    }
+
+    #[test]
+    fn test_input_json() {
+        let sum = find(REGISTRY, 2015, 1).unwrap();
+        let last = find(REGISTRY, 2015, 25).unwrap();
+        let input = sum.parse("1 5 3").unwrap();
+        assert_eq!(sum.input_json(input.as_ref()).unwrap(), "[1,5,3]");
+        assert!(last.input_json(input.as_ref()).is_err());
+        assert_eq!(
+            last.input_json(last.parse("").unwrap().as_ref())
+                .unwrap_err()
+                .to_string(),
+            "2015 day 25 cannot serialize its input"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_to_json() {
+        assert_eq!(to_json(&[1, 2]).unwrap(), "[\n  1,\n  2\n]");
+    }
+
+    #[test]
+    fn test_missing_part() {
+        let last = find(REGISTRY, 2015, 25).unwrap();
+        assert_eq!(last.parts(), &[Part::One]);
+        assert_eq!(last.run("", Part::One).unwrap(), "done");
+        assert!(last.run("", Part::Two).is_err());
+    }
+
+    #[test]
+    fn test_wrong_input() {
+        let sum = find(REGISTRY, 2015, 1).unwrap();
+        let last = find(REGISTRY, 2015, 25).unwrap();
+        let input = last.parse("").unwrap();
+        assert!(sum.solve(input.as_ref(), Part::One).is_err());
+    }
+
+    #[test]
+    fn test_find() {
+        assert!(find(REGISTRY, 2015, 2).is_none());
+        assert!(find(REGISTRY, 2016, 1).is_none());
+    }
+}
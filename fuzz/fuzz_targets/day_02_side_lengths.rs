@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_02_1::extract_side_lengths;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = extract_side_lengths(input);
+});
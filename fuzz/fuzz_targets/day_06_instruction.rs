@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_06_1::{Action, Instruction};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Action::try_from(input);
+    let _ = Instruction::try_from(input);
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use day_07_1::wire::{Gate, Signal, Source, Wire};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Signal::try_from(input);
+    let _ = Gate::try_from(input);
+    let _ = Source::try_from(input);
+    let _ = Wire::try_from(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_09_1::LocationPair;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = LocationPair::try_from(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_13_1::Information;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Information::try_from(input);
+});
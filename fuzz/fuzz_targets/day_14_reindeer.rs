@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_14_1::Reindeer;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Reindeer::try_from(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_15_1::Ingredient;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Ingredient::try_from(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_16_1::MfcsamRecord;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = MfcsamRecord::try_from(input);
+});
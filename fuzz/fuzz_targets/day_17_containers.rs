@@ -0,0 +1,9 @@
+#![no_main]
+
+use common::solution::Solution;
+use day_17_2::Day17;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day17::parse(input);
+});
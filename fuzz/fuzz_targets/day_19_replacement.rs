@@ -0,0 +1,9 @@
+#![no_main]
+
+use day_19_1::{parse_replacement, tokenize_molecule};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = parse_replacement(input);
+    let _ = tokenize_molecule(input);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use common::error::parse_complete;
+use day_21_1::parsers;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = parse_complete(input, parsers::items);
+    let _ = parse_complete(input, parsers::key_value_line);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_22_1::BossStats;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = BossStats::try_from(input);
+});
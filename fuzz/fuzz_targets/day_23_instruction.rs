@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_23_1::Instruction;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Instruction::try_from(input);
+});